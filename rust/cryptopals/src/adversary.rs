@@ -1,34 +1,81 @@
 //! Collection of adversaries that can be used to break encryption or detect
 //! hidden properties
 
+pub use attack_cbc_key_as_iv::attack_cbc_key_as_iv;
+pub use attack_cbc_padding_oracle::attack_cbc_padding_oracle;
+pub use attack_ctr_edit_oracle::attack_ctr_edit_oracle;
+pub use attack_ctr_fixed_nonce::attack_ctr_fixed_nonce;
+pub use attack_dh_mitm::{attack_dh_mitm, DhMitmStrategy};
+pub use attack_dsa_repeated_nonce::attack_dsa_repeated_nonce;
+pub use attack_dsa_small_nonce::attack_dsa_small_nonce;
 pub use attack_ecb_fixed_postfix::attack_ecb_fixed_postfix;
+pub use attack_hmac_timing_leak::attack_hmac_timing_leak;
 pub use attack_repeating_key_xor::attack_repeating_key_xor;
+pub use attack_rsa_broadcast::attack_rsa_broadcast;
+pub use attack_rsa_unpadded::attack_rsa_unpadded;
 pub use attack_single_byte_xor::attack_single_byte_xor;
+pub use attack_srp_dictionary::attack_srp_dictionary;
+pub use attack_srp_zero_key::{attack_srp_zero_key, SrpZeroKeyStrategy};
 pub use average_hamming_distance::average_hamming_distance;
+use channel_result::ChannelResultExt;
+pub use clone_mt19937::clone_mt19937;
 pub use detect_aes_block_mode::detect_aes_block_mode;
 pub use detect_aes_properties::{detect_aes_properties, AesEcbProperties};
 pub use detect_block_size_repeating_key::detect_block_size_repeating_key;
 pub use error::AdversaryError;
 pub use find_aes_ecb_ciphertext::find_aes_ecb_ciphertext;
+use find_english_text::english_likelihood;
 pub use find_english_text::find_english_text;
+use find_prefix_length::find_prefix_length;
 pub use forge_admin_profile::forge_admin_profile;
+pub use forge_cbc_bit_flip::forge_cbc_bit_flip;
+pub use forge_ctr_bit_flip::forge_ctr_bit_flip;
+pub use forge_cut_and_paste::forge_cut_and_paste;
+pub use forge_dsa_signature::forge_dsa_signature;
+pub use forge_length_extension_mac::forge_length_extension_mac;
+pub use forge_rsa_signature::forge_rsa_signature;
 use get_ciphertext_length::get_ciphertext_length;
 use get_duplicated_block_index::get_duplicated_block_index;
+pub use plan_cut_and_paste::{plan_cut_and_paste, CutAndPastePlan, TokenTemplate};
+use recover_dsa_private_key::recover_dsa_private_key;
 use scored_item::ScoredItem;
 
+mod attack_cbc_key_as_iv;
+mod attack_cbc_padding_oracle;
+mod attack_ctr_edit_oracle;
+mod attack_ctr_fixed_nonce;
+mod attack_dh_mitm;
+mod attack_dsa_repeated_nonce;
+mod attack_dsa_small_nonce;
 mod attack_ecb_fixed_postfix;
+mod attack_hmac_timing_leak;
 mod attack_repeating_key_xor;
+mod attack_rsa_broadcast;
+mod attack_rsa_unpadded;
 mod attack_single_byte_xor;
+mod attack_srp_dictionary;
+mod attack_srp_zero_key;
 mod average_hamming_distance;
+mod channel_result;
+mod clone_mt19937;
 mod detect_aes_block_mode;
 mod detect_aes_properties;
 mod detect_block_size_repeating_key;
 mod error;
 mod find_aes_ecb_ciphertext;
 mod find_english_text;
+mod find_prefix_length;
 mod forge_admin_profile;
+mod forge_cbc_bit_flip;
+mod forge_ctr_bit_flip;
+mod forge_cut_and_paste;
+mod forge_dsa_signature;
+mod forge_length_extension_mac;
+mod forge_rsa_signature;
 mod get_ciphertext_length;
 mod get_duplicated_block_index;
+mod plan_cut_and_paste;
+mod recover_dsa_private_key;
 mod scored_item;
 
 // An arbitrary character to use when building a plaintext of a given length.
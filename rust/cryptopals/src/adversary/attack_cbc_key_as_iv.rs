@@ -0,0 +1,56 @@
+use error_stack::{bail, ensure, Result, ResultExt};
+
+use super::{AdversaryError, DEFAULT_BYTE};
+use crate::{
+    aes::{self, BLOCK_LENGTH},
+    byte::*,
+    oracle::{DecryptOracle, Oracle, OracleError},
+};
+
+/// Adversary which recovers the key of an oracle that uses the key as IV in AES CBC mode
+///
+/// ## Approach
+/// The adversary modifies a ciphertext of at least three blocks into `C1 || 0 || C1`, followed by the last two
+/// blocks of the original ciphertext to keep the padding valid. Decrypting this gives:
+///  - `P1 = D(C1) ^ IV`
+///  - `P3 = D(C1) ^ 0`
+///
+/// So `P1 ^ P3` equals the IV, which is the key. The second block decrypts to random bytes, which are very likely
+/// to contain high-ASCII bytes. The oracle will reject this plaintext, but leaks it in the error.
+///
+/// The key is returned as block, since it's also used as IV.
+pub fn attack_cbc_key_as_iv<O: Oracle + DecryptOracle>(oracle: &O) -> Result<aes::Block, AdversaryError> {
+    // Encrypt three blocks, which results in four blocks including the padding
+    let plaintext = ByteSlice::with_repeated_byte_and_length(3 * BLOCK_LENGTH, DEFAULT_BYTE);
+    let ciphertext = oracle
+        .encrypt(plaintext)
+        .change_context(AdversaryError::InvalidInputOracle)?;
+
+    let blocks = ciphertext.chunks(BLOCK_LENGTH).collect::<Vec<_>>();
+    let [first_block, _, third_block, fourth_block] = &blocks[..] else {
+        bail!(AdversaryError::UnexpectedCiphertextLength);
+    };
+
+    // Build C1 || 0 || C1 || C3 || C4
+    let zero_block = ByteSlice::with_repeated_byte_and_length(BLOCK_LENGTH, 0);
+    let modified_ciphertext = first_block + zero_block + first_block + third_block + fourth_block;
+
+    // Catch the plaintext that is leaked by the oracle
+    let plaintext = match oracle.decrypt(modified_ciphertext) {
+        Ok(_) => bail!(AdversaryError::UnableToLeakPlaintext),
+        Err(report) => match report.current_context() {
+            OracleError::NonAsciiPlaintext(plaintext) => plaintext.clone(),
+            _other_error => bail!(report.change_context(AdversaryError::UnableToLeakPlaintext)),
+        },
+    };
+
+    // XOR the first and third block to recover the key
+    ensure!(plaintext.length() >= 3 * BLOCK_LENGTH, AdversaryError::UnexpectedPlaintextLength);
+    let blocks = plaintext.chunks(BLOCK_LENGTH).collect::<Vec<_>>();
+    let bytes = blocks[0]
+        .iter()
+        .zip(blocks[2].iter())
+        .map(|(lhs, rhs)| lhs ^ rhs);
+
+    Ok(aes::Block::from(ByteArray::<BLOCK_LENGTH>::from_iter(bytes)))
+}
@@ -0,0 +1,109 @@
+use super::AdversaryError;
+use crate::{
+    aes::{Block, BLOCK_LENGTH},
+    byte::*,
+    oracle::PaddingOracle,
+};
+use error_stack::{Result, ResultExt};
+use std::{iter, ops::BitXorAssign};
+
+/// Attack an oracle which reveals whether a CBC ciphertext decrypts to a plaintext with valid padding
+///
+/// ## Approach
+/// Each ciphertext block is attacked on its own. In CBC mode, a ciphertext block is decrypted to an intermediate
+/// block, which is XOR-ed with the previous ciphertext block (or IV) to get the plaintext block. By submitting the
+/// ciphertext block with a forged IV, we control the resulting plaintext byte by byte.
+///
+/// Starting with the last byte, we try every value of the forged IV until the oracle accepts the padding. At that
+/// point the plaintext byte must be equal to the padding value, which reveals the intermediate byte.
+///
+/// ```text
+/// intermediate = forged_iv XOR padding
+/// plaintext = intermediate XOR previous_block
+/// ```
+pub fn attack_cbc_padding_oracle<O: PaddingOracle>(
+    oracle: &O,
+    ciphertext: &ByteSlice,
+    iv: &Block,
+) -> Result<ByteSlice<'static>, AdversaryError> {
+    // Split into blocks
+    let blocks = ciphertext
+        .clone()
+        .blocks::<BLOCK_LENGTH>()
+        .change_context(AdversaryError::UnexpectedCiphertextLength)?
+        .map(Block::from)
+        .collect::<Vec<_>>();
+
+    // Each block is XOR-ed with the previous block, where the first block uses the IV
+    let previous_blocks = iter::once(iv).chain(blocks.iter());
+
+    let plaintext_blocks = blocks
+        .iter()
+        .zip(previous_blocks)
+        .map(|(block, previous_block)| {
+            // Recover the intermediate block and undo the XOR with the previous block
+            let mut plaintext_block = get_intermediate_block(oracle, block)?;
+            plaintext_block.bitxor_assign(previous_block);
+
+            Ok(plaintext_block)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Construct plaintext
+    let bytes = plaintext_blocks.into_iter().flat_map(Block::into_iter);
+    let mut plaintext = ByteSlice::from_iter(bytes);
+
+    // Remove padding
+    plaintext
+        .unpad()
+        .change_context(AdversaryError::RecoveredPlaintextHasInvalidPadding)?;
+
+    Ok(plaintext)
+}
+
+// Recover the result of decrypting a single block, before it is XOR-ed with the previous block
+fn get_intermediate_block<O: PaddingOracle>(oracle: &O, block: &Block) -> Result<Block, AdversaryError> {
+    let ciphertext = ByteSlice::from_iter(block.clone().into_iter());
+
+    // Starting with no known bytes
+    let mut intermediate_block = Block::from([0; BLOCK_LENGTH]);
+
+    // Recover the bytes from last to first
+    for index in (0..BLOCK_LENGTH).rev() {
+        // The padding value we want the oracle to accept
+        let padding = (BLOCK_LENGTH - index) as u8;
+
+        // Forge an IV such that all known bytes decrypt to the padding value
+        let mut forged_iv = Block::from([0; BLOCK_LENGTH]);
+        for known_index in (index + 1)..BLOCK_LENGTH {
+            forged_iv[known_index] = intermediate_block[known_index] ^ padding;
+        }
+
+        // Try every byte value until the oracle accepts the padding
+        let byte_value = (0..=255)
+            .find(|&byte_value| {
+                forged_iv[index] = byte_value;
+
+                if !oracle.check_padding(ciphertext.clone(), &forged_iv) {
+                    return false;
+                }
+
+                // For the last byte, the plaintext might accidentally end with valid padding longer than one byte.
+                // (For example 0x02 0x02.) Changing the second-to-last byte rules out these false positives.
+                if index == BLOCK_LENGTH - 1 {
+                    let mut alternative_iv = forged_iv.clone();
+                    alternative_iv[index - 1] ^= 0xFF;
+
+                    return oracle.check_padding(ciphertext.clone(), &alternative_iv);
+                }
+
+                true
+            })
+            .ok_or(AdversaryError::UnableToFindValidPadding)?;
+
+        // The forged IV byte XOR-ed with the intermediate byte resulted in the padding value
+        intermediate_block[index] = byte_value ^ padding;
+    }
+
+    Ok(intermediate_block)
+}
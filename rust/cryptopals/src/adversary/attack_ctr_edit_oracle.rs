@@ -0,0 +1,25 @@
+use error_stack::{Result, ResultExt};
+
+use super::{AdversaryError, DEFAULT_BYTE};
+use crate::{byte::*, oracle::EditOracle};
+
+/// Adversary which recovers the entire plaintext of a CTR ciphertext, using an oracle that allows editing it
+///
+/// ## Approach
+/// Overwriting the entire ciphertext with known bytes reveals the keystream, since the new ciphertext is simply the
+/// known bytes XOR-ed with the keystream. XOR-ing the original ciphertext with the keystream reveals the plaintext.
+pub fn attack_ctr_edit_oracle<O: EditOracle>(
+    oracle: &O,
+    ciphertext: &ByteSlice<'_>,
+) -> Result<ByteSlice<'static>, AdversaryError> {
+    // Overwrite the entire ciphertext with known bytes
+    let known_plaintext = ByteSlice::with_repeated_byte_and_length(ciphertext.length(), DEFAULT_BYTE);
+    let known_ciphertext = oracle
+        .edit(ciphertext, 0, &known_plaintext)
+        .change_context(AdversaryError::InvalidInputOracle)?;
+
+    // Recover the keystream
+    let keystream = known_ciphertext.single_byte_xor(DEFAULT_BYTE);
+
+    Ok(ciphertext.repeated_key_xor(&keystream))
+}
@@ -0,0 +1,109 @@
+use error_stack::{ensure, Result};
+
+use super::{attack_single_byte_xor, AdversaryError};
+use crate::byte::*;
+
+// Flipping this bit of an ASCII letter changes its case
+const CASE_BIT: u8 = 0x20;
+
+/// Adversary which takes many ciphertexts that have been encrypted using AES CTR mode with the same key and nonce,
+/// and tries to recover all plaintexts
+///
+/// ## Approach
+/// Each ciphertext is XOR-ed with the same keystream, so the bytes at the same index of every ciphertext form a column
+/// that is encrypted with a single keystream byte. Each column is attacked as a single byte XOR cipher.
+///
+/// Up to the length of the shortest ciphertext, each column contains a byte of every ciphertext. Past that, the columns
+/// only contain bytes of the ciphertexts that are long enough. Since there are fewer bytes to score, the last bytes of
+/// the longest plaintexts are less reliable.
+///
+/// If a column only contains letters, flipping the case of all of them scores the same. In that case, each plaintext
+/// votes on the case based on the bytes before it, which were already recovered: a capital letter is expected at the
+/// start of the text and after the end of a sentence, and a lowercase letter otherwise.
+pub fn attack_ctr_fixed_nonce(ciphertexts: &[ByteSlice<'_>]) -> Result<Vec<ByteSlice<'static>>, AdversaryError> {
+    ensure!(!ciphertexts.is_empty(), AdversaryError::NotEnoughCiphertexts(1));
+
+    // The keystream is as long as the longest ciphertext
+    let keystream_length = ciphertexts
+        .iter()
+        .map(ByteSequence::length)
+        .max()
+        .unwrap_or_default();
+
+    let mut keystream = Vec::with_capacity(keystream_length);
+    for index in 0..keystream_length {
+        // Get the byte at this index of every ciphertext that is long enough
+        let column = ByteSlice::from_iter(
+            ciphertexts
+                .iter()
+                .filter_map(|ciphertext| ciphertext.get(index).copied()),
+        );
+
+        // Attack the column as a single byte XOR cipher
+        let plaintext = attack_single_byte_xor(&column)?;
+
+        // Recover the keystream byte from the first byte of the column
+        let keystream_byte = column
+            .get(0)
+            .zip(plaintext.get(0))
+            .map(|(lhs, rhs)| lhs ^ rhs)
+            .ok_or(AdversaryError::NotEnoughCiphertexts(1))?;
+
+        keystream.push(resolve_case(ciphertexts, &keystream, keystream_byte));
+    }
+    let keystream = ByteSlice::from(keystream);
+
+    // XOR each ciphertext with the recovered keystream
+    let plaintexts = ciphertexts
+        .iter()
+        .map(|ciphertext| ciphertext.repeated_key_xor(&keystream))
+        .collect();
+
+    Ok(plaintexts)
+}
+
+// Choose the case of the next column if it only contains letters, given the keystream of the preceding columns
+fn resolve_case(ciphertexts: &[ByteSlice<'_>], keystream: &[u8], keystream_byte: u8) -> u8 {
+    let index = keystream.len();
+    let decrypt = |ciphertext: &ByteSlice<'_>, index: usize| {
+        ciphertext
+            .get(index)
+            .map(|byte| byte ^ keystream.get(index).unwrap_or(&keystream_byte))
+    };
+
+    let is_letter_column = ciphertexts
+        .iter()
+        .filter_map(|ciphertext| decrypt(ciphertext, index))
+        .all(|byte| byte.is_ascii_alphabetic());
+    if !is_letter_column {
+        return keystream_byte;
+    }
+
+    // Count the plaintexts that agree with the case of the current guess, minus the ones that disagree
+    let votes = ciphertexts
+        .iter()
+        .filter(|ciphertext| ciphertext.length() > index)
+        .map(|ciphertext| {
+            let letter = decrypt(ciphertext, index).unwrap_or_default();
+
+            // Skip whitespace to find the end of the preceding word
+            let preceding_byte = (0..index)
+                .rev()
+                .filter_map(|index| decrypt(ciphertext, index))
+                .find(|byte| !byte.is_ascii_whitespace());
+            let is_capital_expected = matches!(preceding_byte, None | Some(b'.' | b'!' | b'?'));
+
+            if letter.is_ascii_uppercase() == is_capital_expected {
+                1
+            } else {
+                -1
+            }
+        })
+        .sum::<isize>();
+
+    if votes < 0 {
+        keystream_byte ^ CASE_BIT
+    } else {
+        keystream_byte
+    }
+}
@@ -0,0 +1,146 @@
+use super::{AdversaryError, ChannelResultExt};
+use crate::{
+    aes,
+    byte::*,
+    dh::{self, Channel, Message, Parameters},
+};
+use error_stack::{bail, Result, ResultExt};
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+use std::{
+    panic,
+    sync::{
+        mpsc::{Receiver, Sender},
+        Mutex,
+    },
+    thread,
+};
+
+/// The manipulation of the key exchange by the man-in-the-middle
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DhMitmStrategy {
+    /// Replace both public keys with `p`, so the shared secret is 0
+    PublicKeyFixing,
+    /// Replace the generator with 1, so the shared secret is 1
+    GeneratorOne,
+    /// Replace the generator with `p`, so the shared secret is 0
+    GeneratorP,
+    /// Replace the generator with `p - 1`, so the shared secret is either 1 or `p - 1`
+    GeneratorPMinusOne,
+}
+
+/// Relay a key exchange between two parties, manipulate it and decrypt all messages
+///
+/// ## Approach
+/// The man-in-the-middle sits between the channels of the initiator and the responder. During the key exchange it
+/// manipulates either the parameters or the public keys, such that the shared secret of both parties is predictable:
+///  - `p^a mod p = 0`
+///  - `1^a mod p = 1`
+///  - `(p - 1)^a mod p` is 1 if `a` is even, or `p - 1` if `a` is odd
+///
+/// Afterwards, each message is decrypted with the predicted key and relayed unchanged, so neither party notices the
+/// attack. Both directions are relayed independently, so either party may send several messages in a row. The relay
+/// stops once either party hangs up and returns all decrypted messages.
+pub fn attack_dh_mitm(
+    initiator: Channel,
+    responder: Channel,
+    strategy: DhMitmStrategy,
+) -> Result<Vec<ByteSlice<'static>>, AdversaryError> {
+    // Relay the parameters and the acknowledgement
+    let Message::Parameters(parameters) = initiator.receive().or_unexpected_message()? else {
+        bail!(AdversaryError::UnexpectedMessage);
+    };
+    let p = parameters.p().clone();
+    let parameters = match strategy {
+        DhMitmStrategy::PublicKeyFixing => parameters,
+        DhMitmStrategy::GeneratorOne => Parameters::new(p.clone(), BigUint::one()),
+        DhMitmStrategy::GeneratorP => Parameters::new(p.clone(), p.clone()),
+        DhMitmStrategy::GeneratorPMinusOne => Parameters::new(p.clone(), &p - 1_u32),
+    };
+    responder
+        .send(Message::Parameters(parameters.clone()))
+        .or_unexpected_message()?;
+
+    let Message::Parameters(_) = responder.receive().or_unexpected_message()? else {
+        bail!(AdversaryError::UnexpectedMessage);
+    };
+    initiator
+        .send(Message::Parameters(parameters))
+        .or_unexpected_message()?;
+
+    // Relay the public keys, which are only replaced by key-fixing
+    let forge_public_key = |public_key: &BigUint| match strategy {
+        DhMitmStrategy::PublicKeyFixing => p.clone(),
+        _ => public_key.clone(),
+    };
+
+    let Message::PublicKey(initiator_public_key) = initiator.receive().or_unexpected_message()? else {
+        bail!(AdversaryError::UnexpectedMessage);
+    };
+    responder
+        .send(Message::PublicKey(forge_public_key(&initiator_public_key)))
+        .or_unexpected_message()?;
+
+    let Message::PublicKey(responder_public_key) = responder.receive().or_unexpected_message()? else {
+        bail!(AdversaryError::UnexpectedMessage);
+    };
+    initiator
+        .send(Message::PublicKey(forge_public_key(&responder_public_key)))
+        .or_unexpected_message()?;
+
+    // Predict the shared secret
+    let shared_secret = match strategy {
+        DhMitmStrategy::PublicKeyFixing | DhMitmStrategy::GeneratorP => BigUint::zero(),
+        DhMitmStrategy::GeneratorOne => BigUint::one(),
+        // A public key of 1 means the private key is even, which makes the shared secret 1 as well
+        DhMitmStrategy::GeneratorPMinusOne => match initiator_public_key.is_one() || responder_public_key.is_one() {
+            true => BigUint::one(),
+            false => &p - 1_u32,
+        },
+    };
+    let key = dh::derive_key(&shared_secret);
+
+    // Relay each direction on its own thread, so either party can send any number of messages in a row
+    let (initiator_sender, initiator_receiver) = initiator.split();
+    let (responder_sender, responder_receiver) = responder.split();
+    let plaintexts = Mutex::new(Vec::new());
+
+    thread::scope(|scope| {
+        let forward = scope.spawn(|| relay(initiator_receiver, responder_sender, &key, &plaintexts));
+        let backward = relay(responder_receiver, initiator_sender, &key, &plaintexts);
+
+        forward
+            .join()
+            .unwrap_or_else(|error| panic::resume_unwind(error))
+            .and(backward)
+    })?;
+
+    Ok(plaintexts
+        .into_inner()
+        .unwrap_or_else(|error| error.into_inner()))
+}
+
+// Relay messages in one direction and decrypt them, until either party hangs up
+fn relay(
+    receiver: Receiver<Message>,
+    sender: Sender<Message>,
+    key: &aes::Key,
+    plaintexts: &Mutex<Vec<ByteSlice<'static>>>,
+) -> Result<(), AdversaryError> {
+    while let Ok(message) = receiver.recv() {
+        if let Message::Ciphertext(ciphertext) = &message {
+            let plaintext = aes::cbc::decrypt_with_prepended_iv(ciphertext.clone(), key)
+                .change_context(AdversaryError::UnableToDecryptMessage)?;
+            plaintexts
+                .lock()
+                .unwrap_or_else(|error| error.into_inner())
+                .push(plaintext);
+        }
+
+        if sender.send(message).is_err() {
+            break;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use super::{recover_dsa_private_key, AdversaryError};
+use crate::{
+    byte::*,
+    dsa::{self, PrivateKey, PublicKey, Signature},
+    number_theory::modular_inverse,
+};
+use error_stack::{bail, Result};
+use itermore::IterArrayCombinations;
+
+/// Recover the DSA private key from a list of signed messages, if the signer reused a nonce for two of them
+///
+/// ## Approach
+/// Signatures with the same nonce `k` have the same `r = (g^k mod p) mod q`. For two such signatures, subtracting
+/// `s_i = k^-1 (H(m_i) + x * r) mod q` cancels out the private key, so `k = (H(m_1) - H(m_2)) / (s_1 - s_2) mod q`.
+/// Afterwards, the private key follows from either signature.
+pub fn attack_dsa_repeated_nonce(
+    public_key: &PublicKey,
+    signed_messages: &[(ByteSlice<'_>, Signature)],
+) -> Result<PrivateKey, AdversaryError> {
+    let q = public_key.parameters().q();
+
+    // Find all pairs of signatures with the same r
+    let pairs = signed_messages
+        .iter()
+        .array_combinations()
+        .filter(|[(_, lhs), (_, rhs)]| lhs.r() == rhs.r() && lhs.s() != rhs.s());
+
+    for [(message_1, signature_1), (message_2, signature_2)] in pairs {
+        let hash_1 = dsa::hash_message(message_1);
+        let hash_2 = dsa::hash_message(message_2);
+
+        // Add q to prevent underflow
+        let hash_difference = (&hash_1 % q + q - &hash_2 % q) % q;
+        let s_difference = (signature_1.s() % q + q - signature_2.s() % q) % q;
+
+        let Ok(s_difference_inverse) = modular_inverse(&s_difference, q) else {
+            continue;
+        };
+        let k = hash_difference * s_difference_inverse % q;
+
+        if let Some(private_key) = recover_dsa_private_key(public_key, &hash_1, signature_1, &k) {
+            return Ok(private_key);
+        }
+    }
+
+    bail!(AdversaryError::UnableToRecoverPrivateKey)
+}
@@ -0,0 +1,43 @@
+use super::{recover_dsa_private_key, AdversaryError};
+use crate::{
+    byte::*,
+    dsa::{self, PrivateKey, PublicKey, Signature},
+};
+use error_stack::{bail, Result};
+use num_bigint::BigUint;
+use num_traits::One;
+
+// Number of bits of the nonce that was used by the signer
+const NONCE_BITS: u32 = 16;
+
+/// Recover the DSA private key from a single signature, if the signer used a nonce of at most 16 bits
+///
+/// ## Approach
+/// Given the nonce `k`, the private key follows from the signature as `x = (s * k - H(m)) / r mod q`. The adversary
+/// tries every small nonce, and only calculates the private key if `r = (g^k mod p) mod q`. Each candidate is verified
+/// by comparing `g^x mod p` with the public key.
+pub fn attack_dsa_small_nonce(
+    public_key: &PublicKey,
+    message: &ByteSlice<'_>,
+    signature: &Signature,
+) -> Result<PrivateKey, AdversaryError> {
+    let parameters = public_key.parameters();
+    let (p, q, g) = (parameters.p(), parameters.q(), parameters.g());
+    let message_hash = dsa::hash_message(message);
+
+    // Keep track of g^k mod p, which only takes a single multiplication per nonce
+    let mut power = BigUint::one();
+
+    for k in 1..1_u32 << NONCE_BITS {
+        power = power * g % p;
+        if power.clone() % q != *signature.r() {
+            continue;
+        }
+
+        if let Some(private_key) = recover_dsa_private_key(public_key, &message_hash, signature, &BigUint::from(k)) {
+            return Ok(private_key);
+        }
+    }
+
+    bail!(AdversaryError::UnableToRecoverPrivateKey)
+}
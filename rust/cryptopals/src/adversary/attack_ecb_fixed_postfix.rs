@@ -1,97 +1,197 @@
-use super::{detect_aes_properties, AdversaryError, AesEcbProperties, DEFAULT_BYTE};
+use super::{english_likelihood, AdversaryError, DEFAULT_BYTE};
 use crate::{aes::BLOCK_LENGTH, byte::*, oracle::Oracle};
-use error_stack::{Result, ResultExt};
-use std::collections::HashMap;
-
-// List of printable ASCII characters
-const PRINTABLE_CHARACTERS: [u8; 97] = [
-    9, 10, 13, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57,
-    58, 59, 60, 61, 62, 63, 64, 65, 66, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86,
-    87, 88, 89, 90, 91, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 109, 110, 111,
-    112, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 123, 124, 125,
-];
-
-fn get_nth_block_of_ciphertext<O: Oracle>(
-    oracle: &O,
-    plaintext: ByteSlice<'_>,
-    block_length: usize,
-    block_index: usize,
-) -> Result<ByteSlice<'static>, AdversaryError> {
-    // Try to encrypt it using the oracle
-    let ciphertext = oracle
-        .encrypt(plaintext)
-        .change_context(AdversaryError::InvalidInputOracle)?;
-
-    // Try to find the desired block
-    let block = ciphertext
-        .chunks(block_length)
-        .nth(block_index)
-        .ok_or(AdversaryError::UnexpectedCiphertextLength)?;
-
-    // TODO: solve issue that block is a reference to ciphertext
-    let block = ByteSlice::from_iter(block.into_iter());
-
-    Ok(block)
+use error_stack::{bail, ensure, Result, ResultExt};
+use std::cell::Cell;
+
+// Number of candidate byte values which are encrypted in a single query
+const CANDIDATES_PER_QUERY: usize = 32;
+
+// Value of the first padding byte, when the unknown byte is the last byte of a block
+const PADDING_BYTE: u8 = 1;
+
+// Two sentinel blocks, which are planted twice each in front of the plaintext of the adversary. They start and end with
+// different bytes, so the pattern `A A B B` only appears in the ciphertext when the sentinel starts at a new block.
+const SENTINEL_BLOCKS: [&[u8; BLOCK_LENGTH]; 2] = [b"YELLOW SUBMARINE", b"SUBMARINE YELLOW"];
+
+// Maximum number of queries to get a single aligned ciphertext
+const MAXIMUM_ATTEMPTS: usize = 32 * BLOCK_LENGTH;
+
+// A block of ciphertext
+type CiphertextBlock = ByteArray<BLOCK_LENGTH>;
+
+// Wrapper around an oracle which returns the ciphertext blocks as if there was no prefix
+struct AlignedOracle<'a, O: Oracle> {
+    oracle: &'a O,
+    padding_length: Cell<usize>,
+}
+
+impl<'a, O: Oracle> AlignedOracle<'a, O> {
+    fn new(oracle: &'a O) -> Self {
+        AlignedOracle {
+            oracle,
+            padding_length: Cell::new(0),
+        }
+    }
+
+    // Encrypt the plaintext and only return the ciphertext blocks of the plaintext and postfix.
+    //
+    // The sentinel is preceded by some padding. If the prefix has a fixed length, one of the padding lengths will align
+    // the sentinel with the start of a block, and this padding length is remembered for the next query. If the prefix
+    // has a random length, the query is simply repeated until the prefix happens to align the sentinel.
+    //
+    // Visualization:
+    // - block length is 4
+    // - random prefix is r
+    // - padding is p
+    // - sentinel blocks are A and B
+    // - plaintext is x
+    // - fixed postfix is f
+    //
+    // rrrr rrrp AAAA AAAA BBBB BBBB [xxxx xfff fff]
+    fn encrypt(&self, plaintext: &ByteSlice<'_>) -> Result<Vec<CiphertextBlock>, AdversaryError> {
+        let sentinel = SENTINEL_BLOCKS
+            .into_iter()
+            .flat_map(|block| [block, block])
+            .flatten()
+            .copied();
+
+        for _ in 0..MAXIMUM_ATTEMPTS {
+            let padding_length = self.padding_length.get();
+            let padding = ByteSlice::with_repeated_byte_and_length(padding_length, DEFAULT_BYTE);
+
+            let ciphertext = self
+                .oracle
+                .encrypt(padding + ByteSlice::from_iter(sentinel.clone()) + plaintext)
+                .change_context(AdversaryError::InvalidInputOracle)?;
+
+            let mut blocks = ciphertext
+                .blocks::<BLOCK_LENGTH>()
+                .change_context(AdversaryError::UnexpectedCiphertextLength)?
+                .collect::<Vec<_>>();
+
+            // Look for two pairs of duplicated blocks, which are different from each other
+            let position = blocks
+                .windows(4)
+                .position(|window| window[0] == window[1] && window[2] == window[3] && window[0] != window[2]);
+
+            if let Some(position) = position {
+                return Ok(blocks.split_off(position + 4));
+            }
+
+            // Try another padding length next time
+            self.padding_length.set((padding_length + 1) % BLOCK_LENGTH);
+        }
+
+        bail!(AdversaryError::UnableToAlignPlaintext)
+    }
+}
+
+// Encrypt one block for each candidate, where each block contains the known bytes followed by the candidate. Return the
+// candidate of which the block matches the target block.
+fn find_byte_value<O: Oracle>(
+    oracle: &AlignedOracle<'_, O>,
+    known_bytes: &[u8],
+    candidates: &[u8],
+    target_block: &CiphertextBlock,
+) -> Result<Option<u8>, AdversaryError> {
+    let plaintext = ByteSlice::from_iter(
+        candidates
+            .iter()
+            .flat_map(|candidate| known_bytes.iter().chain([candidate]))
+            .copied(),
+    );
+
+    let blocks = oracle.encrypt(&plaintext)?;
+    let position = blocks
+        .iter()
+        .take(candidates.len())
+        .position(|block| block == target_block);
+
+    Ok(position.map(|index| candidates[index]))
 }
 
 /// Attack the the postfix of an Oracle encrypting with ECB mode
+///
+/// The oracle may prepend a prefix to the plaintext, which can either be fixed or random for every query. In order to
+/// know where the plaintext starts, the adversary plants a sentinel in front of it.
+///
+/// All byte values are tried, in order of how likely they are to occur in English text. The attack ends once the first
+/// byte of the PKCS#7 padding is recovered. If a byte cannot be recovered, the bytes recovered so far are reported in
+/// the error.
 pub fn attack_ecb_fixed_postfix<O: Oracle>(oracle: &O) -> Result<ByteSlice<'static>, AdversaryError> {
-    let AesEcbProperties {
-        postfix_length,
-        prefix_length,
-        alignment_offset: _,
-    } = detect_aes_properties(oracle)?;
+    let oracle = AlignedOracle::new(oracle);
+
+    // Order all byte values from most to least likely
+    let mut candidates = (0..=255).collect::<Vec<u8>>();
+    candidates.sort_by(|lhs, rhs| english_likelihood(rhs).total_cmp(&english_likelihood(lhs)));
 
     // Starting with no known characters
-    let mut known_characters = ByteSlice::from(Vec::new());
+    let mut known_characters = Vec::new();
+
+    // Brute force each character of the fixed postfix one by one, until the padding is reached
+    loop {
+        let byte_index = known_characters.len();
+        let stalled = || AdversaryError::PostfixRecoveryStalled(ByteSlice::from(known_characters.clone()));
 
-    // Brute force each character of the fixed postfix one by one
-    for byte_index in prefix_length..(prefix_length + postfix_length) {
         // Calculate the block number within the plaintext/cipher which will contain the
         // character we are looking for
-        let block_index = (prefix_length + byte_index) / BLOCK_LENGTH;
+        let block_index = byte_index / BLOCK_LENGTH;
 
         // Prefix all plaintexts with a specific number of bytes to align the
         // character we are looking for in the last position of a block in the ciphertext
-        let alignment_offset = (block_index + 1) * BLOCK_LENGTH - byte_index - 1;
+        let alignment_offset = BLOCK_LENGTH - 1 - byte_index % BLOCK_LENGTH;
         let alignment_text = ByteSlice::with_repeated_byte_and_length(alignment_offset, DEFAULT_BYTE);
 
-        // Build a map of encrypted blocks where the corresponding plaintext block was
-        // our prefix + known characters + a printable byte value
-        let mut block_map = PRINTABLE_CHARACTERS
-            .into_iter()
-            .map(|byte_value| {
-                // Create plaintexts from the prefix, followed by all known characters, followed
-                // by byte value
-                let mut plaintext = &alignment_text + &known_characters;
-                plaintext.push(byte_value);
-
-                // Store the block of the ciphertext containing the different bytes values in
-                // the last position
-                let block = get_nth_block_of_ciphertext(oracle, plaintext, BLOCK_LENGTH, block_index)?;
+        // Encrypt only the alignment text, so the character is the last byte of a block
+        //
+        // Visualization:
+        // - block length is 4
+        // - fixed postfix has 5 characters (2 are known `k` and 3 unknown `u`)
+        // - alignment text is a
+        //
+        // aakk [uuu]
+        let blocks = oracle
+            .encrypt(&alignment_text)
+            .change_context_lazy(stalled)?;
+        let target_block = blocks.get(block_index).ok_or_else(stalled)?;
 
-                Ok((block, byte_value))
-            })
-            .collect::<Result<HashMap<_, _>, _>>()?;
+        // The block ends with the last bytes of the alignment text and known characters
+        let preceding_bytes = alignment_text
+            .iter()
+            .chain(known_characters.iter())
+            .copied()
+            .collect::<Vec<_>>();
+        let preceding_bytes = &preceding_bytes[preceding_bytes.len() - (BLOCK_LENGTH - 1)..];
 
-        // Encrypt only the prefix and lookup the block in the dictionary
+        // Try the most likely byte values first, a batch at a time
         //
         // Visualization:
         // - block length is 4
-        // - prefix is p
-        // - fixed postfix has 5 characters (2 are known `k` and 3 unknown `u`)
-        // - printable byte value is B
+        // - byte values are B
         //
-        // pppp [pkkB] kkuu u
-        // pppp [pkku] uu
-        let block = get_nth_block_of_ciphertext(oracle, alignment_text, BLOCK_LENGTH, block_index)?;
+        // [kuuB] [kuuB] [kuuB] ...
+        let mut byte_value = None;
+        for candidates in candidates.chunks(CANDIDATES_PER_QUERY) {
+            byte_value =
+                find_byte_value(&oracle, preceding_bytes, candidates, target_block).change_context_lazy(stalled)?;
+
+            if byte_value.is_some() {
+                break;
+            }
+        }
+        let byte_value = byte_value.ok_or_else(stalled)?;
+
+        // The last byte of the last block can only be padding, so the entire postfix is known
+        if block_index == blocks.len() - 1 {
+            ensure!(byte_value == PADDING_BYTE, stalled());
+            break;
+        }
 
         // Add the discovered character to the list of known characters so it can be
         // used in the next step
-        let byte_value = block_map.remove(&block).unwrap();
         known_characters.push(byte_value);
     }
 
     // All characters are known, this must be the fixed postfix
-    Ok(known_characters)
+    Ok(ByteSlice::from(known_characters))
 }
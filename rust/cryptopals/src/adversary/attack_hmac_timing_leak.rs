@@ -0,0 +1,185 @@
+use super::{AdversaryError, ScoredItem};
+use crate::{
+    byte::*,
+    hash::{Hash, Sha1},
+    oracle::VerifyOracle,
+};
+use error_stack::{bail, Result};
+use std::{panic, thread, time::Instant};
+
+// Maximum number of bytes to recover, including the ones that are recovered again after backtracking
+const MAXIMUM_ATTEMPTS: usize = 4 * Sha1::DIGEST_LENGTH;
+
+// Maximum number of signatures that are verified at the same time
+const MAXIMUM_CONCURRENCY: usize = 64;
+
+// Number of byte values that are measured again after measuring every byte value once
+const FINALISTS: usize = 8;
+
+/// Recover a valid HMAC-SHA1 signature of a file purely from the response time of the oracle
+///
+/// ## Approach
+/// The oracle compares the signature byte by byte, exits early at the first difference and takes a bit of time after
+/// each matching byte. So if all bytes before index `i` are known, the byte value at index `i` which leads to the
+/// longest response time is most likely correct.
+///
+/// ## Statistical mode
+/// A single measurement is not reliable, since any delay in the oracle (or network) might be mistaken for a matching
+/// byte. Therefore, the byte values with the longest response times are measured another `samples` times. Noise only
+/// ever adds time, so the minimum over all measurements is used as the response time. When the leak is small compared
+/// to the noise, use a larger number of samples.
+///
+/// Even the minimum response time is not reliable when there is a longer burst of noise. However, once a byte is
+/// correct, the runner-up at the next index will take about as long as the previous winner did. If it only takes about
+/// as long as the previous runner-up did, the adversary backtracks and recovers the previous byte again.
+///
+/// The last byte does not leak any timing information, but can be found by checking which signature is accepted.
+///
+/// The byte values are measured concurrently, so the delays of the oracle overlap. Otherwise, recovering a signature
+/// from an oracle with a leak of a few milliseconds would take minutes.
+pub fn attack_hmac_timing_leak<O: VerifyOracle + Sync>(
+    oracle: &O,
+    file: &ByteSlice<'_>,
+    samples: usize,
+) -> Result<ByteSlice<'static>, AdversaryError> {
+    // Starting with no known bytes
+    let mut known_bytes = Vec::new();
+
+    // Runner-up and winning response time of each known byte
+    let mut response_times: Vec<(f32, f32)> = Vec::new();
+
+    for _ in 0..MAXIMUM_ATTEMPTS {
+        let index = known_bytes.len();
+
+        // Build a signature from the known bytes, followed by the byte value and zeroes for the remaining bytes
+        let build_signature = |byte_value: u8| {
+            let zeroes = (index + 1..Sha1::DIGEST_LENGTH).map(|_| 0);
+            ByteSlice::from_iter(
+                known_bytes
+                    .iter()
+                    .copied()
+                    .chain([byte_value])
+                    .chain(zeroes),
+            )
+        };
+        let signatures = (0..=255).map(build_signature).collect::<Vec<_>>();
+
+        // Find the last byte by checking which signature is valid
+        if index == Sha1::DIGEST_LENGTH - 1 {
+            let is_valid = map_concurrently(&signatures, |signature| oracle.verify(file, signature));
+
+            match signatures
+                .into_iter()
+                .zip(is_valid)
+                .find(|(_, is_valid)| *is_valid)
+            {
+                Some((signature, _)) => return Ok(signature),
+                None => {
+                    // One of the earlier bytes must be wrong, so try the previous byte again
+                    known_bytes.pop();
+                    response_times.pop();
+                    continue;
+                }
+            }
+        }
+
+        // Measure every byte value once, and keep the finalists with the longest response times
+        let mut candidates = map_concurrently(&signatures, |signature| measure_response_time(oracle, file, signature))
+            .into_iter()
+            .zip(0..=255)
+            .map(|(duration, byte_value)| ScoredItem::new(duration, byte_value))
+            .collect::<Vec<_>>();
+        candidates.sort_by(|lhs, rhs| rhs.cmp(lhs));
+        candidates.truncate(FINALISTS);
+
+        // Measure the finalists again and keep the minimum response time
+        let (mut durations, byte_values): (Vec<_>, Vec<_>) = candidates
+            .into_iter()
+            .map(|candidate| (candidate.score(), candidate.item()))
+            .unzip();
+        let signatures = byte_values
+            .iter()
+            .copied()
+            .map(build_signature)
+            .collect::<Vec<_>>();
+        for _ in 0..samples {
+            let sample = map_concurrently(&signatures, |signature| measure_response_time(oracle, file, signature));
+            for (duration, sample) in durations.iter_mut().zip(sample) {
+                *duration = duration.min(sample);
+            }
+        }
+
+        // Order from shortest to longest response time
+        let mut candidates = durations
+            .into_iter()
+            .zip(byte_values)
+            .map(|(duration, byte_value)| ScoredItem::new(duration, byte_value))
+            .collect::<Vec<_>>();
+        candidates.sort();
+
+        let winner = candidates.pop().ok_or(AdversaryError::EmptyCandidateList)?;
+        let (shortest, runner_up) = match (candidates.first(), candidates.last()) {
+            (Some(shortest), Some(runner_up)) => (shortest.score(), runner_up.score()),
+            _ => bail!(AdversaryError::EmptyCandidateList),
+        };
+
+        // If the previous byte is correct, the runner-up takes about as long as the previous winner did. Otherwise, it
+        // takes about as long as the previous runner-up did.
+        if let Some((previous_runner_up, previous_winner)) = response_times.last() {
+            if runner_up < (previous_runner_up + previous_winner) / 2.0 {
+                known_bytes.pop();
+                response_times.pop();
+                continue;
+            }
+        }
+
+        // The winner should stand out from the other finalists. Otherwise, the correct byte value was not among the
+        // finalists, so measure this index again.
+        if winner.score() - runner_up < runner_up - shortest {
+            continue;
+        }
+
+        response_times.push((runner_up, winner.score()));
+        known_bytes.push(winner.item());
+    }
+
+    bail!(AdversaryError::UnableToRecoverSignature)
+}
+
+// Apply the function to each signature on a separate thread, so the delays of the oracle overlap
+//
+// The number of concurrent threads is limited, since overloading the oracle adds noise to the response times.
+fn map_concurrently<T: Send>(
+    signatures: &[ByteSlice<'static>],
+    function: impl Fn(&ByteSlice<'static>) -> T + Sync,
+) -> Vec<T> {
+    let function = &function;
+
+    signatures
+        .chunks(MAXIMUM_CONCURRENCY)
+        .flat_map(|signatures| {
+            thread::scope(|scope| {
+                let handles = signatures
+                    .iter()
+                    .map(|signature| scope.spawn(move || function(signature)))
+                    .collect::<Vec<_>>();
+
+                handles
+                    .into_iter()
+                    .map(|handle| {
+                        handle
+                            .join()
+                            .unwrap_or_else(|error| panic::resume_unwind(error))
+                    })
+                    .collect::<Vec<_>>()
+            })
+        })
+        .collect()
+}
+
+// Measure how long it takes (in seconds) for the oracle to verify the signature
+fn measure_response_time<O: VerifyOracle>(oracle: &O, file: &ByteSlice<'_>, signature: &ByteSlice<'_>) -> f32 {
+    let start = Instant::now();
+    oracle.verify(file, signature);
+    start.elapsed().as_secs_f32()
+}
@@ -0,0 +1,48 @@
+use super::AdversaryError;
+use crate::{
+    byte::*,
+    number_theory,
+    rsa::{PublicKey, PUBLIC_EXPONENT},
+};
+use error_stack::{ensure, Result, ResultExt};
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+
+/// Recover a plaintext that was encrypted to three different public keys with `e = 3` (Håstad's broadcast attack)
+///
+/// ## Approach
+/// Since `c_i = m^3 mod n_i` for each of the three public keys, the Chinese remainder theorem gives `m^3` modulo
+/// `n_0 * n_1 * n_2`. The plaintext is smaller than each modulus, so `m^3` is smaller than the product of the moduli.
+/// Therefore, the result of the Chinese remainder theorem is exactly `m^3`, and the integer cube root reveals `m`.
+pub fn attack_rsa_broadcast(ciphertexts: &[(PublicKey, BigUint)]) -> Result<ByteSlice<'static>, AdversaryError> {
+    ensure!(ciphertexts.len() >= 3, AdversaryError::NotEnoughCiphertexts(3));
+
+    let ciphertexts = &ciphertexts[..3];
+    for (public_key, _) in ciphertexts {
+        ensure!(*public_key.e() == BigUint::from(PUBLIC_EXPONENT), AdversaryError::UnexpectedPublicExponent);
+    }
+
+    // Product of all moduli
+    let product = ciphertexts
+        .iter()
+        .map(|(public_key, _)| public_key.n())
+        .fold(BigUint::one(), |product, n| product * n);
+
+    // Chinese remainder theorem
+    let mut cube = BigUint::zero();
+    for (public_key, ciphertext) in ciphertexts {
+        // Product of the other moduli
+        let m_s = &product / public_key.n();
+        let inverse =
+            number_theory::modular_inverse(&m_s, public_key.n()).change_context(AdversaryError::ModuliNotCoprime)?;
+
+        cube += ciphertext * &m_s * inverse;
+    }
+    cube %= &product;
+
+    // The result should be a perfect cube
+    let plaintext = cube.cbrt();
+    ensure!(plaintext.pow(3) == cube, AdversaryError::UnableToFindCubeRoot);
+
+    Ok(ByteSlice::from(&plaintext))
+}
@@ -0,0 +1,42 @@
+use super::AdversaryError;
+use crate::{byte::*, number_theory, oracle::DecryptOracle, rsa::PublicKey};
+use error_stack::{Result, ResultExt};
+use num_bigint::{BigUint, RandBigInt};
+use rand::Rng;
+
+/// Recover the plaintext of an unpadded RSA ciphertext from an oracle that refuses to decrypt it
+///
+/// ## Approach
+/// Raw RSA is multiplicative, so `(s^e * c)^d = s * m mod n` for any `s`. The adversary picks a random `s`, lets the
+/// oracle decrypt the blinded ciphertext `s^e * c mod n`, which has a different hash than the original ciphertext, and
+/// multiplies the result by the modular inverse of `s`.
+pub fn attack_rsa_unpadded<O>(
+    oracle: &O,
+    public_key: &PublicKey,
+    ciphertext: &ByteSlice<'_>,
+    rng: &mut impl Rng,
+) -> Result<ByteSlice<'static>, AdversaryError>
+where
+    O: DecryptOracle<Output = ByteSlice<'static>>,
+{
+    let n = public_key.n();
+
+    // Pick a random factor that is invertible modulo n
+    let (factor, inverse) = loop {
+        let factor = rng.gen_biguint_range(&BigUint::from(2_u32), n);
+        if let Ok(inverse) = number_theory::modular_inverse(&factor, n) {
+            break (factor, inverse);
+        }
+    };
+
+    // Blind the ciphertext
+    let blinded_ciphertext = public_key.encrypt(&factor) * BigUint::from(ciphertext) % n;
+    let blinded_plaintext = oracle
+        .decrypt(ByteSlice::from(&blinded_ciphertext))
+        .change_context(AdversaryError::InvalidInputOracle)?;
+
+    // Divide out the factor
+    let plaintext = BigUint::from(&blinded_plaintext) * inverse % n;
+
+    Ok(ByteSlice::from(&plaintext))
+}
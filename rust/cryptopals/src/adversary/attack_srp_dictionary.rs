@@ -0,0 +1,68 @@
+use super::{AdversaryError, ChannelResultExt};
+use crate::{
+    byte::*,
+    dh::Parameters,
+    srp::{self, Channel, Message},
+};
+use error_stack::{bail, Result};
+use num_bigint::BigUint;
+use num_traits::One;
+use rand::Rng;
+
+// Number of bytes in the salt that the adversary sends
+const SALT_LENGTH: usize = 16;
+
+/// Pose as a simplified SRP server, capture a single login and crack the password of the client using a dictionary.
+/// The salt is generated using the given random number generator.
+///
+/// ## Approach
+/// In simplified SRP the public key `B` and scrambling parameter `u` of the server do not depend on the verifier, so
+/// the adversary can send them without knowing the password. It sends `B = g` and `u = 1`, so that the client
+/// calculates the shared secret `S = B^(a + u * x) = A * g^x mod N`.
+///
+/// Afterwards, the adversary tries each candidate password offline. It calculates `x` from the salt and candidate,
+/// derives the session key and compares the proof with the one that the client sent.
+pub fn attack_srp_dictionary<'a>(
+    channel: &Channel,
+    parameters: &Parameters,
+    candidates: impl IntoIterator<Item = &'a str>,
+    rng: &mut impl Rng,
+) -> Result<String, AdversaryError> {
+    let (n, g) = (parameters.p(), parameters.g());
+
+    // Capture a single login
+    let Message::Login { public_key, .. } = channel.receive().or_unexpected_message()? else {
+        bail!(AdversaryError::UnexpectedMessage);
+    };
+
+    let salt = ByteSlice::with_random_values_and_length(SALT_LENGTH, rng);
+    channel
+        .send(Message::SimplifiedChallenge {
+            salt: salt.clone(),
+            public_key: g.clone(),
+            scrambler: BigUint::one(),
+        })
+        .or_unexpected_message()?;
+
+    let Message::Proof(proof) = channel.receive().or_unexpected_message()? else {
+        bail!(AdversaryError::UnexpectedMessage);
+    };
+
+    // Accept the login, so the client does not notice the attack
+    channel
+        .send(Message::LoginResult(true))
+        .or_unexpected_message()?;
+
+    // Crack the password offline
+    let password = candidates
+        .into_iter()
+        .find(|candidate| {
+            let x = srp::hash_password(&salt, candidate);
+            let shared_secret = &public_key * g.modpow(&x, n) % n;
+
+            srp::prove_key(&srp::derive_key(&shared_secret), &salt) == proof
+        })
+        .ok_or(AdversaryError::PasswordNotInDictionary)?;
+
+    Ok(password.to_string())
+}
@@ -0,0 +1,61 @@
+use super::{AdversaryError, ChannelResultExt};
+use crate::{
+    dh::Parameters,
+    srp::{self, Channel, Message},
+};
+use error_stack::{bail, ensure, Result};
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+/// The public key that the adversary sends instead of `A = g^a mod N`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SrpZeroKeyStrategy {
+    /// Send 0 as public key
+    Zero,
+    /// Send `N` as public key
+    Modulus,
+    /// Send `2N` as public key
+    DoubleModulus,
+}
+
+/// Log in to an SRP server as the given user, without knowing the password
+///
+/// ## Approach
+/// The server calculates the shared secret as `S = (A * v^u)^b mod N`. If the public key `A` of the client is a
+/// multiple of `N`, the shared secret is 0 regardless of the verifier `v`. Therefore, the adversary can calculate the
+/// session key and send a valid proof.
+pub fn attack_srp_zero_key(
+    channel: &Channel,
+    parameters: &Parameters,
+    email: &str,
+    strategy: SrpZeroKeyStrategy,
+) -> Result<(), AdversaryError> {
+    let public_key = match strategy {
+        SrpZeroKeyStrategy::Zero => BigUint::zero(),
+        SrpZeroKeyStrategy::Modulus => parameters.p().clone(),
+        SrpZeroKeyStrategy::DoubleModulus => parameters.p() * 2_u32,
+    };
+    channel
+        .send(Message::Login {
+            email: email.to_string(),
+            public_key,
+        })
+        .or_unexpected_message()?;
+
+    // The shared secret is 0 for both variants of the protocol
+    let salt = match channel.receive().or_unexpected_message()? {
+        Message::Challenge { salt, .. } | Message::SimplifiedChallenge { salt, .. } => salt,
+        _ => bail!(AdversaryError::UnexpectedMessage),
+    };
+    let key = srp::derive_key(&BigUint::zero());
+    channel
+        .send(Message::Proof(srp::prove_key(&key, &salt)))
+        .or_unexpected_message()?;
+
+    let Message::LoginResult(is_accepted) = channel.receive().or_unexpected_message()? else {
+        bail!(AdversaryError::UnexpectedMessage);
+    };
+    ensure!(is_accepted, AdversaryError::LoginRejected);
+
+    Ok(())
+}
@@ -0,0 +1,16 @@
+use super::AdversaryError;
+use crate::CryptopalsError;
+use error_stack::{Result, ResultExt};
+
+/// Extension for the results of sending or receiving a message on a channel, during a protocol that the adversary takes
+/// part in
+pub trait ChannelResultExt<T> {
+    /// Treat a failure, like the other party hanging up, as an unexpected turn of the protocol
+    fn or_unexpected_message(self) -> Result<T, AdversaryError>;
+}
+
+impl<T> ChannelResultExt<T> for Result<T, CryptopalsError> {
+    fn or_unexpected_message(self) -> Result<T, AdversaryError> {
+        self.change_context(AdversaryError::UnexpectedMessage)
+    }
+}
@@ -0,0 +1,79 @@
+use super::AdversaryError;
+use crate::rng::{
+    Mt19937, STATE_LENGTH, TEMPERING_MASK_B, TEMPERING_MASK_C, TEMPERING_SHIFT_L, TEMPERING_SHIFT_S, TEMPERING_SHIFT_T,
+    TEMPERING_SHIFT_U,
+};
+use error_stack::{ensure, Result};
+
+/// Clone a MT19937 generator from 624 consecutive outputs
+///
+/// Each output is a tempered word of the internal state. Since the tempering transform is invertible, untempering
+/// 624 consecutive outputs recovers the entire internal state. The outputs should start right after a twist, which
+/// is the case for the first 624 outputs of a freshly seeded generator.
+///
+/// The cloned generator will predict all future outputs of the original generator.
+pub fn clone_mt19937(outputs: &[u32]) -> Result<Mt19937, AdversaryError> {
+    ensure!(outputs.len() >= STATE_LENGTH, AdversaryError::NotEnoughOutputs(STATE_LENGTH));
+
+    // Untemper each output to recover the internal state
+    let mut state = [0; STATE_LENGTH];
+    for (word, output) in state.iter_mut().zip(outputs) {
+        *word = untemper(*output);
+    }
+
+    // The original generator has output every word of this state, so the clone will continue from the next twist
+    Ok(Mt19937::from_state(state))
+}
+
+// Invert the tempering transform of MT19937
+fn untemper(mut value: u32) -> u32 {
+    value = undo_right_shift_xor(value, TEMPERING_SHIFT_L);
+    value = undo_left_shift_and_xor(value, TEMPERING_SHIFT_T, TEMPERING_MASK_C);
+    value = undo_left_shift_and_xor(value, TEMPERING_SHIFT_S, TEMPERING_MASK_B);
+    value = undo_right_shift_xor(value, TEMPERING_SHIFT_U);
+
+    value
+}
+
+// Invert `value ^= value >> shift`
+//
+// The highest `shift` bits are unchanged, which reveals the next `shift` bits, and so on.
+fn undo_right_shift_xor(value: u32, shift: u32) -> u32 {
+    let mut result = value;
+    for _ in 0..(32 / shift) {
+        result = value ^ (result >> shift);
+    }
+
+    result
+}
+
+// Invert `value ^= (value << shift) & mask`
+//
+// The lowest `shift` bits are unchanged, which reveals the next `shift` bits, and so on.
+fn undo_left_shift_and_xor(value: u32, shift: u32, mask: u32) -> u32 {
+    let mut result = value;
+    for _ in 0..(32 / shift) {
+        result = value ^ ((result << shift) & mask);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn untemper_inverts_temper() {
+        for value in [0, 1, 0xdeadbeef, 0x80000000, u32::MAX] {
+            // Temper using the same steps as MT19937
+            let mut tempered = value;
+            tempered ^= tempered >> TEMPERING_SHIFT_U;
+            tempered ^= (tempered << TEMPERING_SHIFT_S) & TEMPERING_MASK_B;
+            tempered ^= (tempered << TEMPERING_SHIFT_T) & TEMPERING_MASK_C;
+            tempered ^= tempered >> TEMPERING_SHIFT_L;
+
+            assert_eq!(untemper(tempered), value);
+        }
+    }
+}
@@ -1,12 +1,18 @@
-use super::{get_duplicated_block_index, AdversaryError};
+use super::{get_ciphertext_length, get_duplicated_block_index, AdversaryError};
 use crate::{
     aes::{BlockMode, BLOCK_LENGTH},
     oracle::Oracle,
 };
 use error_stack::Result;
 
-/// Detect whether an oracle is encrypting with ECB or CBC block cipher mode.
+/// Detect whether an oracle is encrypting with ECB, CBC or CTR block cipher mode.
 pub fn detect_aes_block_mode<O: Oracle>(oracle: &O) -> Result<BlockMode, AdversaryError> {
+    // CTR mode does not use padding, so adding a single byte to the plaintext adds a single byte to the ciphertext.
+    // Both ECB and CBC mode will either add zero bytes or an entire block.
+    if get_ciphertext_length(oracle, 1)? == get_ciphertext_length(oracle, 0)? + 1 {
+        return Ok(BlockMode::Ctr);
+    }
+
     // Purposefully chosen string for detecting AES ECB block mode.
     // The string contains an arbitrary character 48 times in a row.
     // After encrypting this plaintext with AES ECB mode, the cipher should have at
@@ -1,3 +1,4 @@
+use crate::byte::*;
 use thiserror::Error;
 
 /// Error enum for errors in adversary
@@ -21,6 +22,9 @@ pub enum AdversaryError {
     /// The ciphertext provided by the oracle had less blocks than expected.
     #[error("The ciphertext provided by the oracle had less blocks than expected.")]
     UnexpectedCiphertextLength,
+    /// The plaintext leaked by the oracle had less blocks than expected.
+    #[error("The plaintext leaked by the oracle had less blocks than expected.")]
+    UnexpectedPlaintextLength,
     /// The oracle uses an unexpected block length
     #[error("The oracle uses an unexpected block length of {0}.")]
     UnexpectedBlockLength(usize),
@@ -30,4 +34,76 @@ pub enum AdversaryError {
     /// The oracle always returns the same ciphertext length.
     #[error("The oracle always returns the same ciphertext length.")]
     OracleAlwaysReturnsSameCiphertextLength,
+    /// The padding oracle did not accept any value for a byte.
+    #[error("The padding oracle did not accept any value for a byte.")]
+    UnableToFindValidPadding,
+    /// The plaintext recovered by the adversary does not have valid padding.
+    #[error("The plaintext recovered by the adversary does not have valid padding.")]
+    RecoveredPlaintextHasInvalidPadding,
+    /// The adversary needs more outputs of the generator.
+    #[error("The adversary needs at least {0} outputs of the generator.")]
+    NotEnoughOutputs(usize),
+    /// The digest does not have the length of the hash function.
+    #[error("The digest has an unexpected length of {0}.")]
+    UnexpectedDigestLength(usize),
+    /// The oracle did not accept any of the forged MACs.
+    #[error("The oracle did not accept any of the forged MACs.")]
+    UnableToForgeMac,
+    /// The oracle did not accept any of the recovered signatures.
+    #[error("The oracle did not accept any of the recovered signatures.")]
+    UnableToRecoverSignature,
+    /// One of the parties sent an unexpected message or hung up during the key exchange.
+    #[error("One of the parties sent an unexpected message or hung up during the key exchange.")]
+    UnexpectedMessage,
+    /// The message could not be decrypted with the predicted key.
+    #[error("The message could not be decrypted with the predicted key.")]
+    UnableToDecryptMessage,
+    /// The adversary needs more ciphertexts of the same plaintext.
+    #[error("The adversary needs at least {0} ciphertexts of the same plaintext.")]
+    NotEnoughCiphertexts(usize),
+    /// The public key does not use the expected public exponent.
+    #[error("The public key does not use the expected public exponent.")]
+    UnexpectedPublicExponent,
+    /// The moduli of the public keys are not pairwise coprime.
+    #[error("The moduli of the public keys are not pairwise coprime.")]
+    ModuliNotCoprime,
+    /// The recovered value is not a perfect cube.
+    #[error("The recovered value is not a perfect cube.")]
+    UnableToFindCubeRoot,
+    /// Unable to find the offset of the plaintext that is controlled by the adversary.
+    #[error("Unable to find the offset of the plaintext that is controlled by the adversary.")]
+    UnableToFindControlledOffset,
+    /// The oracle did not leak the plaintext.
+    #[error("The oracle did not leak the plaintext.")]
+    UnableToLeakPlaintext,
+    /// The sentinel of the adversary never started at a new block.
+    #[error("The sentinel of the adversary never started at a new block.")]
+    UnableToAlignPlaintext,
+    /// The adversary was unable to recover the next byte of the postfix, but did recover the bytes before it.
+    #[error("Unable to recover the next byte of the postfix after recovering: {0:?}")]
+    PostfixRecoveryStalled(ByteSlice<'static>),
+    /// The token template does not contain exactly one controlled field, or disallows the filler byte.
+    #[error("The token template does not contain exactly one controlled field, or disallows the filler byte.")]
+    InvalidTokenTemplate,
+    /// Not every block of the forged token can be cut from a ciphertext of the oracle.
+    #[error("Not every block of the forged token can be cut from a ciphertext of the oracle.")]
+    UnableToPlanCutAndPaste,
+    /// The injected target string would overlap the ciphertext block that is scrambled by flipping its bits.
+    #[error("The injected target string would overlap the ciphertext block that is scrambled by flipping its bits.")]
+    InjectionOverlapsScrambledBlock,
+    /// The server rejected the login of the adversary.
+    #[error("The server rejected the login of the adversary.")]
+    LoginRejected,
+    /// None of the candidate passwords match the captured login.
+    #[error("None of the candidate passwords match the captured login.")]
+    PasswordNotInDictionary,
+    /// The modulus is too short to fit the signature block of the forged signature.
+    #[error("The modulus is too short to fit the signature block of the forged signature.")]
+    UnableToForgeSignature,
+    /// None of the candidate nonces reveal the private key.
+    #[error("None of the candidate nonces reveal the private key.")]
+    UnableToRecoverPrivateKey,
+    /// The generator of the domain parameters is neither 0 nor p + 1.
+    #[error("The generator of the domain parameters is neither 0 nor p + 1.")]
+    GeneratorNotTampered,
 }
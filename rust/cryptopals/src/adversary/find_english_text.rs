@@ -71,6 +71,13 @@ fn char_index(code_point: &u8) -> Option<usize> {
     }
 }
 
+/// Return how likely the code point is to occur in English text, where unprintable characters are never expected.
+pub(super) fn english_likelihood(code_point: &u8) -> f32 {
+    char_index(code_point)
+        .map(|index| EXPECTED_FREQUENCY[index])
+        .unwrap_or_default()
+}
+
 /// Calculate score for candidate based on how closely it resembles English
 /// text. The lower the score, the more resemblance to English.
 /// Inspiration: https://crypto.stackexchange.com/a/30259/103927
@@ -0,0 +1,52 @@
+use super::{AdversaryError, DEFAULT_BYTE};
+use crate::{aes::BLOCK_LENGTH, byte::*, oracle::Oracle};
+use error_stack::{bail, Result, ResultExt};
+
+// A second arbitrary character to build a plaintext that differs from the default one in a single byte
+const ALTERNATIVE_BYTE: u8 = b'V';
+
+/// Find the number of bytes that an oracle using a block cipher prepends to the plaintext.
+///
+/// A single byte of the plaintext is changed at increasing offsets. The first ciphertext block that differs moves to the
+/// next block once the changed byte crosses a block boundary. Unlike `detect_aes_properties`, this does not rely on
+/// duplicated blocks, so it also works for chained modes like CBC.
+pub fn find_prefix_length<O: Oracle>(oracle: &O) -> Result<usize, AdversaryError> {
+    // Index of the first block that differs from a reference ciphertext, when the byte at the given offset is changed
+    let reference = oracle
+        .encrypt(ByteSlice::with_repeated_byte_and_length(BLOCK_LENGTH + 1, DEFAULT_BYTE))
+        .change_context(AdversaryError::InvalidInputOracle)?;
+    let differing_block_index = |offset: usize| -> Result<usize, AdversaryError> {
+        let plaintext = (0..=BLOCK_LENGTH).map(|index| {
+            if index == offset {
+                ALTERNATIVE_BYTE
+            } else {
+                DEFAULT_BYTE
+            }
+        });
+
+        let ciphertext = oracle
+            .encrypt(ByteSlice::from_iter(plaintext))
+            .change_context(AdversaryError::InvalidInputOracle)?;
+
+        let index = ciphertext
+            .iter()
+            .zip(reference.iter())
+            .position(|(lhs, rhs)| lhs != rhs)
+            .ok_or(AdversaryError::UnableToFindControlledOffset)?;
+
+        Ok(index / BLOCK_LENGTH)
+    };
+
+    // The first changed byte is in the same block as the end of the prefix
+    let prefix_block_index = differing_block_index(0)?;
+
+    // Find how many bytes it takes to fill up the last block of the prefix
+    for offset in 1..=BLOCK_LENGTH {
+        if differing_block_index(offset)? > prefix_block_index {
+            return Ok((prefix_block_index + 1) * BLOCK_LENGTH - offset);
+        }
+    }
+
+    // Changing a byte in the next block should always change the next ciphertext block
+    bail!(AdversaryError::UnableToFindControlledOffset)
+}
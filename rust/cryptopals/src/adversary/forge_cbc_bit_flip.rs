@@ -0,0 +1,48 @@
+use super::{find_prefix_length, AdversaryError, DEFAULT_BYTE};
+use crate::{aes::BLOCK_LENGTH, byte::*, oracle::Oracle};
+use error_stack::{ensure, Result, ResultExt};
+
+/// Adversary which injects an arbitrary target string into the ciphertext of an oracle that uses AES CBC mode
+///
+/// The target string will start at the given offset within the plaintext that is controlled by the adversary.
+///
+/// ## Approach
+/// The oracle might reject special characters in the plaintext, so the target string cannot be encrypted directly.
+/// However, in CBC mode flipping a bit of a ciphertext block flips the same bit in the next plaintext block. Therefore, a
+/// harmless placeholder of the same length is encrypted, and the preceding ciphertext block is XOR-ed with the
+/// difference between the placeholder and the target string.
+///
+/// The block which is flipped will decrypt to random bytes, so the target string must fit in a single block and cannot
+/// be in the first block of the ciphertext. Otherwise, the injection would overlap this sacrificial scrambled block.
+pub fn forge_cbc_bit_flip<O: Oracle>(
+    oracle: &O,
+    target: &ByteSlice<'_>,
+    offset: usize,
+) -> Result<ByteSlice<'static>, AdversaryError> {
+    let length = target.length();
+    let start = find_prefix_length(oracle)? + offset;
+
+    // The blocks of the target string should come after the blocks that are scrambled
+    let first_block = start / BLOCK_LENGTH;
+    let last_block = (start + length).saturating_sub(1) / BLOCK_LENGTH;
+    ensure!(first_block > 0 && last_block <= first_block, AdversaryError::InjectionOverlapsScrambledBlock);
+
+    // Encrypt a placeholder at the desired offset
+    let ciphertext = oracle
+        .encrypt(ByteSlice::with_repeated_byte_and_length(offset + length, DEFAULT_BYTE))
+        .change_context(AdversaryError::InvalidInputOracle)?;
+    ensure!(ciphertext.length() >= start + length, AdversaryError::UnexpectedCiphertextLength);
+
+    // Flip the bits of the preceding block, so the placeholder decrypts to the target string
+    let bytes = ciphertext.iter().enumerate().map(|(index, byte)| {
+        match (index + BLOCK_LENGTH)
+            .checked_sub(start)
+            .and_then(|index| target.get(index))
+        {
+            Some(target_byte) => byte ^ DEFAULT_BYTE ^ target_byte,
+            None => *byte,
+        }
+    });
+
+    Ok(ByteSlice::from_iter(bytes))
+}
@@ -0,0 +1,49 @@
+use error_stack::{Result, ResultExt};
+
+use super::{AdversaryError, DEFAULT_BYTE};
+use crate::{byte::*, oracle::Oracle};
+
+// A second arbitrary character to build a placeholder that differs from the default one in every byte
+const ALTERNATIVE_BYTE: u8 = b'V';
+
+/// Adversary which injects an arbitrary target string into the ciphertext of an oracle that uses AES CTR mode
+///
+/// ## Approach
+/// The oracle might reject special characters in the plaintext, so the target string cannot be encrypted directly.
+/// However, in CTR mode flipping a bit of the ciphertext flips the same bit of the plaintext. Therefore, a harmless
+/// placeholder of the same length is encrypted, and the ciphertext is XOR-ed with the difference between the
+/// placeholder and the target string.
+///
+/// The offset of the placeholder is found by encrypting two different placeholders, since only the bytes at the
+/// controlled offset will differ between the two ciphertexts.
+pub fn forge_ctr_bit_flip<O: Oracle>(oracle: &O, target: &ByteSlice<'_>) -> Result<ByteSlice<'static>, AdversaryError> {
+    let length = target.length();
+
+    // Encrypt two different placeholders
+    let ciphertext = oracle
+        .encrypt(ByteSlice::with_repeated_byte_and_length(length, DEFAULT_BYTE))
+        .change_context(AdversaryError::InvalidInputOracle)?;
+    let alternative_ciphertext = oracle
+        .encrypt(ByteSlice::with_repeated_byte_and_length(length, ALTERNATIVE_BYTE))
+        .change_context(AdversaryError::InvalidInputOracle)?;
+
+    // The first difference marks the start of the placeholder
+    let offset = ciphertext
+        .iter()
+        .zip(alternative_ciphertext.iter())
+        .position(|(lhs, rhs)| lhs != rhs)
+        .ok_or(AdversaryError::UnableToFindControlledOffset)?;
+
+    // Flip the bits of the placeholder into the target string
+    let bytes = ciphertext.iter().enumerate().map(|(index, byte)| {
+        match index
+            .checked_sub(offset)
+            .and_then(|index| target.get(index))
+        {
+            Some(target_byte) => byte ^ DEFAULT_BYTE ^ target_byte,
+            None => *byte,
+        }
+    });
+
+    Ok(ByteSlice::from_iter(bytes))
+}
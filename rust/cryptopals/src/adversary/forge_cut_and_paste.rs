@@ -0,0 +1,41 @@
+use super::{plan_cut_and_paste, AdversaryError, TokenTemplate};
+use crate::{aes::BLOCK_LENGTH, byte::*, oracle::Oracle};
+use error_stack::{report, Result, ResultExt};
+
+/// Forge a token by splicing together blocks of ciphertexts of an oracle using AES ECB mode
+///
+/// The queries and blocks are computed by `plan_cut_and_paste`, based on the template of the token.
+pub fn forge_cut_and_paste<O: Oracle>(
+    oracle: &O,
+    template: &TokenTemplate,
+) -> Result<ByteSlice<'static>, AdversaryError> {
+    let plan = plan_cut_and_paste(template)?;
+
+    // Let the oracle encrypt each query
+    let ciphertexts = plan
+        .queries()
+        .iter()
+        .map(|query| {
+            oracle
+                .encrypt(query.clone())
+                .change_context(AdversaryError::InvalidInputOracle)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Cut the blocks from the ciphertexts
+    let blocks = plan
+        .blocks()
+        .iter()
+        .map(|(query_index, block_index)| {
+            ciphertexts[*query_index]
+                .chunks(BLOCK_LENGTH)
+                .nth(*block_index)
+                .ok_or(report!(AdversaryError::UnexpectedCiphertextLength))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Paste them together
+    let bytes = blocks.into_iter().flat_map(ByteSequence::into_iter);
+
+    Ok(ByteSlice::from_iter(bytes))
+}
@@ -0,0 +1,43 @@
+use super::AdversaryError;
+use crate::{
+    dsa::{PublicKey, Signature},
+    number_theory::modular_inverse,
+};
+use error_stack::{bail, Result};
+use num_bigint::{BigUint, RandBigInt};
+use num_traits::{One, Zero};
+use rand::Rng;
+
+/// Forge a DSA signature which is valid for any message, if the generator of the domain parameters was tampered with
+///
+/// ## Approach
+/// The verifier calculates `v = (g^u1 * y^u2 mod p) mod q` and compares it with `r`:
+///  - if `g = 0`, then `v = 0`, so the signature `r = 0` is valid if the verifier does not check the range of `r`
+///  - if `g = p + 1`, then `g^u1 = 1 mod p`, so `v = (y^u2 mod p) mod q` where `u2 = r / s mod q`. For a random `z`,
+///    the signature `r = (y^z mod p) mod q` and `s = r / z mod q` has `u2 = z`, which makes it valid. The value `z` is
+///    generated using the given random number generator.
+pub fn forge_dsa_signature(public_key: &PublicKey, rng: &mut impl Rng) -> Result<Signature, AdversaryError> {
+    let parameters = public_key.parameters();
+    let (p, q, g) = (parameters.p(), parameters.q(), parameters.g());
+
+    if g.is_zero() {
+        return Ok(Signature::new(BigUint::zero(), BigUint::one()));
+    }
+
+    if *g != p + 1_u32 {
+        bail!(AdversaryError::GeneratorNotTampered);
+    }
+
+    // Pick a random z that is invertible modulo q
+    let (z, z_inverse) = loop {
+        let z = rng.gen_biguint_range(&BigUint::one(), q);
+        if let Ok(z_inverse) = modular_inverse(&z, q) {
+            break (z, z_inverse);
+        }
+    };
+
+    let r = public_key.y().modpow(&z, p) % q;
+    let s = &r * z_inverse % q;
+
+    Ok(Signature::new(r, s))
+}
@@ -0,0 +1,49 @@
+use super::AdversaryError;
+use crate::{byte::*, hash::Hash, oracle::VerifyOracle};
+use error_stack::{ensure, report, Result, ResultExt};
+
+// The maximum key length to try
+const MAX_KEY_LENGTH: usize = 64;
+
+/// # Forge a secret-prefix MAC using a length extension attack
+///
+/// Hash functions that use the Merkle–Damgård construction (such as MD4 and SHA-1) output their entire internal state
+/// after processing `key || message || padding`. By loading this state into our own instance of the hash function, we
+/// can continue hashing additional data without knowing the key.
+///
+/// The resulting MAC is valid for the message below, where the glue padding is the padding the hash function originally
+/// added.
+/// ```text
+/// key || message || glue_padding || extension
+/// ```
+///
+/// ## Assumptions
+/// The length of the key is unknown, but it is needed to calculate the glue padding. Therefore, each key length is
+/// tried until the oracle accepts the forged MAC.
+pub fn forge_length_extension_mac<H: Hash, O: VerifyOracle>(
+    oracle: &O,
+    message: &ByteSlice<'_>,
+    mac: &ByteSlice<'_>,
+    extension: &ByteSlice<'_>,
+) -> Result<(ByteSlice<'static>, ByteSlice<'static>), AdversaryError> {
+    ensure!(mac.length() == H::DIGEST_LENGTH, AdversaryError::UnexpectedDigestLength(mac.length()));
+
+    for key_length in 0..=MAX_KEY_LENGTH {
+        // The padding that was added to the original message
+        let glue_padding = H::padding(key_length + message.length());
+        let forged_message = message + &glue_padding + extension;
+
+        // Resume hashing after the original message and glue padding
+        let mut hash = H::from_digest(mac, key_length + message.length() + glue_padding.length())
+            .change_context(AdversaryError::UnexpectedDigestLength(mac.length()))?;
+        hash.update(extension);
+        let forged_mac = hash.finalize();
+
+        // Ask the oracle whether the guessed key length was correct
+        if oracle.verify(&forged_message, &forged_mac) {
+            return Ok((forged_message, forged_mac));
+        }
+    }
+
+    Err(report!(AdversaryError::UnableToForgeMac))
+}
@@ -0,0 +1,51 @@
+use super::AdversaryError;
+use crate::{
+    byte::*,
+    rsa::{self, DigestAlgorithm, PublicKey, PUBLIC_EXPONENT},
+};
+use error_stack::{ensure, Result};
+use num_bigint::BigUint;
+
+/// Forge a PKCS#1 v1.5 signature of an arbitrary message for a public key with `e = 3`, which is accepted by a
+/// verifier that does not check that the digest is right-justified (Bleichenbacher's attack)
+///
+/// ## Approach
+/// The sloppy verifier only looks at the start of the signature block `00 01 FF 00 DigestInfo`, and ignores the
+/// garbage bytes after it. Any integer between this prefix followed by all 0x00 bytes and the same prefix followed by all
+/// 0xff bytes is accepted. The adversary takes the cube root of the lower bound rounded up, which is a valid signature
+/// as long as its cube does not exceed the upper bound. No modular reduction takes place, because the cube is smaller
+/// than the modulus.
+///
+/// This works if the garbage is about two thirds of the signature block, so the modulus should be long enough compared
+/// to the digest.
+pub fn forge_rsa_signature<H: DigestAlgorithm>(
+    public_key: &PublicKey,
+    message: &ByteSlice<'_>,
+) -> Result<ByteSlice<'static>, AdversaryError> {
+    ensure!(*public_key.e() == BigUint::from(PUBLIC_EXPONENT), AdversaryError::UnexpectedPublicExponent);
+
+    // The start of the signature block with the shortest possible padding
+    let prefix = ByteSlice::from([0x00, 0x01, 0xff, 0x00].as_ref()) + rsa::encode_digest_info::<H>(&H::digest(message));
+
+    let length = public_key.length();
+    let garbage_length = length
+        .checked_sub(prefix.length())
+        .ok_or(AdversaryError::UnableToForgeSignature)?;
+
+    // Range of signature blocks that the sloppy verifier accepts
+    let lower_bound = BigUint::from(&(&prefix + ByteSlice::with_repeated_byte_and_length(garbage_length, 0x00)));
+    let upper_bound = BigUint::from(&(&prefix + ByteSlice::with_repeated_byte_and_length(garbage_length, 0xff)));
+
+    // Round the cube root up, so the cube is at least the lower bound
+    let mut signature = lower_bound.cbrt();
+    if signature.pow(3) < lower_bound {
+        signature += 1_u32;
+    }
+    ensure!(signature.pow(3) <= upper_bound, AdversaryError::UnableToForgeSignature);
+
+    // The signature has the same length as the modulus
+    let signature = ByteSlice::from(&signature);
+    let padding = ByteSlice::with_repeated_byte_and_length(length - signature.length(), 0x00);
+
+    Ok(padding + signature)
+}
@@ -0,0 +1,225 @@
+use super::AdversaryError;
+use crate::{aes::BLOCK_LENGTH, byte::*};
+use error_stack::{bail, ensure, Result};
+
+// Byte that is used in the controlled field, wherever its value does not matter
+const FILLER_BYTE: u8 = b'a';
+
+// Maximum length of the controlled field in any query, which is enough to align any block
+const MAXIMUM_CONTROLLED_LENGTH: usize = 4 * BLOCK_LENGTH;
+
+/// Format of the plaintext of a token, which an oracle encrypts using AES ECB mode.
+///
+/// The template consists of segments in the order in which they appear in the plaintext. Exactly one segment should be
+/// controlled by the adversary.
+///
+/// ## Examples
+/// ```
+/// use cryptopals::adversary::TokenTemplate;
+///
+/// // Format of a token like `email=foo@bar.com&uid=10&role=user`
+/// let template = TokenTemplate::default()
+///     .fixed("email=")
+///     .controlled()
+///     .fixed("&uid=")
+///     .variable(2)
+///     .fixed("&role=")
+///     .substitute("user", "admin")
+///     .disallow(b"&=");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TokenTemplate {
+    segments: Vec<Segment>,
+    disallowed_bytes: Vec<u8>,
+}
+
+// Part of a token template
+#[derive(Clone, Debug)]
+enum Segment {
+    Fixed(Vec<u8>),
+    Controlled,
+    Variable(usize),
+    Substitution { original: Vec<u8>, replacement: Vec<u8> },
+}
+
+// A single byte of a rendered template
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Position {
+    Byte(u8),
+    Controlled(usize),
+    // Index of the segment in the template, and offset of the byte within the segment
+    Variable { segment: usize, offset: usize },
+}
+
+impl TokenTemplate {
+    /// Add bytes which are the same in every token
+    pub fn fixed(mut self, value: &str) -> Self {
+        self.segments
+            .push(Segment::Fixed(value.as_bytes().to_vec()));
+        self
+    }
+
+    /// Add the field which is controlled by the adversary, i.e. the plaintext of the oracle
+    pub fn controlled(mut self) -> Self {
+        self.segments.push(Segment::Controlled);
+        self
+    }
+
+    /// Add bytes of a known length, but an unknown value that might differ between tokens (e.g. a counter)
+    pub fn variable(mut self, length: usize) -> Self {
+        self.segments.push(Segment::Variable(length));
+        self
+    }
+
+    /// Add bytes which the oracle always fills in with the original value, but should be replaced in the forged token
+    pub fn substitute(mut self, original: &str, replacement: &str) -> Self {
+        self.segments.push(Segment::Substitution {
+            original: original.as_bytes().to_vec(),
+            replacement: replacement.as_bytes().to_vec(),
+        });
+        self
+    }
+
+    /// Add bytes which the oracle does not accept in the controlled field
+    pub fn disallow(mut self, bytes: &[u8]) -> Self {
+        self.disallowed_bytes.extend_from_slice(bytes);
+        self
+    }
+
+    // Render the template including PKCS#7 padding, either as the oracle would or as the forged token should be
+    fn render(&self, controlled_length: usize, is_substituted: bool) -> Vec<Position> {
+        let mut positions = Vec::new();
+
+        for (segment_index, segment) in self.segments.iter().enumerate() {
+            match segment {
+                Segment::Fixed(bytes) => positions.extend(bytes.iter().copied().map(Position::Byte)),
+                Segment::Controlled => positions.extend((0..controlled_length).map(Position::Controlled)),
+                Segment::Variable(length) => positions.extend((0..*length).map(|offset| Position::Variable {
+                    segment: segment_index,
+                    offset,
+                })),
+                Segment::Substitution {
+                    original,
+                    replacement,
+                } => {
+                    let bytes = if is_substituted { replacement } else { original };
+                    positions.extend(bytes.iter().copied().map(Position::Byte));
+                }
+            }
+        }
+
+        let padding_length = BLOCK_LENGTH - positions.len() % BLOCK_LENGTH;
+        positions.extend((0..padding_length).map(|_| Position::Byte(padding_length as u8)));
+
+        positions
+    }
+}
+
+/// The queries for an oracle, and which blocks of their ciphertexts to splice together into the forged ciphertext
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct CutAndPastePlan {
+    queries: Vec<ByteSlice<'static>>,
+    blocks: Vec<(usize, usize)>,
+}
+
+impl CutAndPastePlan {
+    /// Plaintexts which should be encrypted by the oracle
+    pub fn queries(&self) -> &[ByteSlice<'static>] {
+        &self.queries
+    }
+
+    /// For each block of the forged ciphertext, the index of the query and the index of the block in its ciphertext
+    pub fn blocks(&self) -> &[(usize, usize)] {
+        &self.blocks
+    }
+}
+
+/// # Plan an ECB cut-and-paste attack on a structured token
+///
+/// ECB mode encrypts each block independently, so blocks from different ciphertexts can be spliced together as long as
+/// each plaintext block appears somewhere at the start of a block.
+///
+/// ## Approach
+/// For a forged token with a controlled field of increasing length, every block of the plaintext is looked up in the
+/// tokens the oracle would create. A block can be taken from a token if the fixed bytes are equal, and the remaining
+/// bytes fall in the controlled field and are allowed. The bytes of variable segments may only line up with the byte at
+/// the same offset of the same segment.
+///
+/// The plan uses the first controlled field length for which every block can be found.
+pub fn plan_cut_and_paste(template: &TokenTemplate) -> Result<CutAndPastePlan, AdversaryError> {
+    let controlled_segments = template
+        .segments
+        .iter()
+        .filter(|segment| matches!(segment, Segment::Controlled))
+        .count();
+    ensure!(controlled_segments == 1, AdversaryError::InvalidTokenTemplate);
+    ensure!(!template.disallowed_bytes.contains(&FILLER_BYTE), AdversaryError::InvalidTokenTemplate);
+
+    for controlled_length in 0..=MAXIMUM_CONTROLLED_LENGTH {
+        // The forged token has filler bytes in the controlled field
+        let forged_token = template
+            .render(controlled_length, true)
+            .into_iter()
+            .map(|position| match position {
+                Position::Controlled(_) => Position::Byte(FILLER_BYTE),
+                position => position,
+            })
+            .collect::<Vec<_>>();
+
+        if let Some(plan) = plan_blocks(template, &forged_token) {
+            return Ok(plan);
+        }
+    }
+
+    bail!(AdversaryError::UnableToPlanCutAndPaste)
+}
+
+// Find a source for every block of the forged token
+fn plan_blocks(template: &TokenTemplate, forged_token: &[Position]) -> Option<CutAndPastePlan> {
+    let mut plan = CutAndPastePlan::default();
+
+    for block in forged_token.chunks(BLOCK_LENGTH) {
+        let (query, block_index) = find_source(template, block)?;
+
+        // Reuse the same query for multiple blocks
+        let query_index = match plan.queries.iter().position(|other| other == &query) {
+            Some(index) => index,
+            None => {
+                plan.queries.push(query);
+                plan.queries.len() - 1
+            }
+        };
+
+        plan.blocks.push((query_index, block_index));
+    }
+
+    Some(plan)
+}
+
+// Find a plaintext for the controlled field, such that the token contains the block at the start of a block
+fn find_source(template: &TokenTemplate, block: &[Position]) -> Option<(ByteSlice<'static>, usize)> {
+    for controlled_length in 0..=MAXIMUM_CONTROLLED_LENGTH {
+        let token = template.render(controlled_length, false);
+
+        'blocks: for (block_index, source) in token.chunks(BLOCK_LENGTH).enumerate() {
+            let mut controlled = vec![FILLER_BYTE; controlled_length];
+
+            for (target, source) in block.iter().zip(source) {
+                match (target, source) {
+                    (Position::Byte(lhs), Position::Byte(rhs)) if lhs == rhs => {}
+                    (Position::Variable { .. }, Position::Variable { .. }) if target == source => {}
+                    (Position::Byte(byte), Position::Controlled(index))
+                        if !template.disallowed_bytes.contains(byte) =>
+                    {
+                        controlled[*index] = *byte;
+                    }
+                    _mismatch => continue 'blocks,
+                }
+            }
+
+            return Some((ByteSlice::from(controlled), block_index));
+        }
+    }
+
+    None
+}
@@ -0,0 +1,24 @@
+use crate::{
+    dsa::{PrivateKey, PublicKey, Signature},
+    number_theory::modular_inverse,
+};
+use num_bigint::BigUint;
+
+// Calculate the private key `x = (s * k - H(m)) / r mod q` from a signature and its nonce, and return it if it matches
+// the public key.
+pub fn recover_dsa_private_key(
+    public_key: &PublicKey,
+    message_hash: &BigUint,
+    signature: &Signature,
+    k: &BigUint,
+) -> Option<PrivateKey> {
+    let parameters = public_key.parameters();
+    let q = parameters.q();
+
+    let r_inverse = modular_inverse(signature.r(), q).ok()?;
+    let x = (signature.s() * k % q + q - message_hash % q) * r_inverse % q;
+
+    // Verify the guess using the public key
+    let private_key = PrivateKey::new(parameters.clone(), x);
+    (private_key.public_key() == public_key).then_some(private_key)
+}
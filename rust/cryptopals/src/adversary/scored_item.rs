@@ -23,6 +23,11 @@ impl<T> ScoredItem<T> {
         ScoredItem { score, item }
     }
 
+    /// Get the score
+    pub fn score(&self) -> f32 {
+        self.score
+    }
+
     /// Get the value back out of the Box
     pub fn item(self) -> T {
         // Box::into_inner(self.item)
@@ -69,9 +74,9 @@ mod tests {
         // List of strings in alphabetical order
         let mut list = Vec::from([
             ScoredItem::new(0.99, "one"),
-            ScoredItem::new(3.14, "pi"),
-            ScoredItem::new(6.28, "tau"),
-            ScoredItem::new(2.72, "e"),
+            ScoredItem::new(std::f32::consts::PI, "pi"),
+            ScoredItem::new(std::f32::consts::TAU, "tau"),
+            ScoredItem::new(std::f32::consts::E, "e"),
             ScoredItem::new(0.00, "zero"),
         ]);
 
@@ -17,6 +17,7 @@ pub use key::Key;
 mod block;
 mod byte_operator;
 pub mod cbc;
+pub mod ctr;
 pub mod ecb;
 mod key;
 
@@ -27,6 +28,8 @@ pub enum BlockMode {
     Ecb,
     /// Cipher block chaining (CBC) mode
     Cbc,
+    /// Counter (CTR) mode
+    Ctr,
 }
 
 // Following the steps of https://kavaliro.com/wp-content/uploads/2014/03/AES.pdf
@@ -189,4 +192,56 @@ mod tests {
         block.decrypt(&key);
         assert_eq!(block, plaintext);
     }
+
+    // Key expansion examples from FIPS-197 appendix A
+    #[test]
+    fn fips_197_key_expansion() {
+        let key_128 = Key::from(hex!("2b7e151628aed2a6abf7158809cf4f3c"));
+        let key_192 = Key::from(hex!("8e73b0f7da0e6452c810f32b809079e562f8ead2522c6b7b"));
+        let key_256 = Key::from(hex!("603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4"));
+
+        // Verify the last round key of each key size
+        let expected = [
+            (key_128, 10, hex!("d014f9a8c9ee2589e13f0cc8b6630ca6")),
+            (key_192, 12, hex!("e98ba06f448c773c8ecc720401002202")),
+            (key_256, 14, hex!("fe4890d1e6188d0b046df344706c631e")),
+        ];
+
+        for (key, number_of_rounds, last_round_key) in expected {
+            assert_eq!(key.number_of_rounds(), number_of_rounds);
+            assert_eq!(key.rounds().last().unwrap(), (number_of_rounds, &Block::from(last_round_key)));
+        }
+    }
+
+    // Example vectors from FIPS-197 appendix C
+    #[test]
+    fn fips_197_example_vectors() {
+        let plaintext = Block::from(hex!("00112233445566778899aabbccddeeff"));
+
+        let examples = [
+            (
+                Key::from(hex!("000102030405060708090a0b0c0d0e0f")),
+                Block::from(hex!("69c4e0d86a7b0430d8cdb78070b4c55a")),
+            ),
+            (
+                Key::from(hex!("000102030405060708090a0b0c0d0e0f1011121314151617")),
+                Block::from(hex!("dda97ca4864cdfe06eaf70a0ec0d7191")),
+            ),
+            (
+                Key::from(hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f")),
+                Block::from(hex!("8ea2b7ca516745bfeafc49904b496089")),
+            ),
+        ];
+
+        for (key, ciphertext) in examples {
+            // Verify encryption
+            let mut block = plaintext.clone();
+            block.encrypt(&key);
+            assert_eq!(block, ciphertext);
+
+            // Verify decryption
+            block.decrypt(&key);
+            assert_eq!(block, plaintext);
+        }
+    }
 }
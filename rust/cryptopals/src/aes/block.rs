@@ -144,8 +144,8 @@ impl Block {
                 // Perform shift rows on every round after round 0
                 self.shift_rows();
 
-                if round_number < 10 {
-                    // Perform mix columns on rounds 1 through 9
+                if round_number < key.number_of_rounds() {
+                    // Perform mix columns on every round except the last
                     self.mix_columns();
                 }
             }
@@ -162,8 +162,8 @@ impl Block {
             self.bitxor_assign(round_key);
 
             if 0 < round_number {
-                if round_number < 10 {
-                    // Perform mix columns on rounds 1 through 9
+                if round_number < key.number_of_rounds() {
+                    // Perform mix columns on every round except the last
                     self.inverse_mix_columns();
                 }
 
@@ -16,11 +16,33 @@
 //! ```
 use super::{Block, Key, BLOCK_LENGTH};
 use crate::{byte::*, CryptopalsError};
-use error_stack::Result;
+use error_stack::{ensure, Result};
 use std::ops::BitXorAssign;
 
-/// AES encrypt using cipher block chaining (CBC) mode.
-pub fn encrypt(mut plaintext: ByteSlice, key: &Key) -> ByteSlice<'static> {
+/// AES encrypt using cipher block chaining (CBC) mode with an all-zero initialization vector.
+pub fn encrypt(plaintext: ByteSlice, key: &Key) -> ByteSlice<'static> {
+    encrypt_with_iv(plaintext, key, &Block::from(ByteArray::with_repeated_byte(0)))
+}
+
+/// AES encrypt using cipher block chaining (CBC) mode with the given initialization vector.
+///
+/// ## Examples
+/// ```
+/// use cryptopals::{aes, byte::*};
+///
+/// let key = aes::Key::from(*b"YELLOW SUBMARINE");
+/// let iv = aes::Block::from(*b"SUBMARINE YELLOW");
+/// let plaintext = ByteSlice::from("https://cryptopals.com/");
+///
+/// let ciphertext = aes::cbc::encrypt_with_iv(plaintext.clone(), &key, &iv);
+///
+/// // Using a different IV leads to a different ciphertext
+/// assert_ne!(ciphertext, aes::cbc::encrypt(plaintext.clone(), &key));
+///
+/// assert_eq!(aes::cbc::decrypt_with_iv(ciphertext, &key, &iv)?, plaintext);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn encrypt_with_iv(mut plaintext: ByteSlice, key: &Key, iv: &Block) -> ByteSlice<'static> {
     // Pad with additional characters
     plaintext.pad(BLOCK_LENGTH);
 
@@ -42,8 +64,8 @@ pub fn encrypt(mut plaintext: ByteSlice, key: &Key) -> ByteSlice<'static> {
 
         // Initialization vector ...
         let iv = match index {
-            // ... from all zeroes
-            0 => &Block::from(ByteArray::with_repeated_byte(0)),
+            // ... from the given IV
+            0 => iv,
             // ... from previous round
             _ => left
                 .get(index - 1)
@@ -67,8 +89,44 @@ pub fn encrypt(mut plaintext: ByteSlice, key: &Key) -> ByteSlice<'static> {
     ByteSlice::from_iter(bytes)
 }
 
-/// AES decrypt using cipher block chaining (CBC) mode.
+/// AES encrypt using cipher block chaining (CBC) mode with a random initialization vector.
+///
+/// The initialization vector is prepended to the ciphertext, so it can be used during decryption.
+///
+/// ## Examples
+/// ```
+/// use cryptopals::{aes, byte::*};
+///
+/// let mut rng = rand::thread_rng();
+/// let key = aes::Key::from(*b"YELLOW SUBMARINE");
+/// let plaintext = ByteSlice::from("https://cryptopals.com/");
+///
+/// let ciphertext = aes::cbc::encrypt_with_random_iv(plaintext.clone(), &key, &mut rng);
+///
+/// // One additional block for the IV
+/// assert_eq!(ciphertext.length(), 3 * aes::BLOCK_LENGTH);
+///
+/// assert_eq!(aes::cbc::decrypt_with_prepended_iv(ciphertext, &key)?, plaintext);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn encrypt_with_random_iv(plaintext: ByteSlice, key: &Key, rng: &mut impl rand::Rng) -> ByteSlice<'static> {
+    // Generate a random IV
+    let iv = Block::with_random_values(rng);
+
+    // Encrypt using the IV
+    let ciphertext = encrypt_with_iv(plaintext, key, &iv);
+
+    // Prepend the IV to the ciphertext
+    ByteSlice::from_iter(iv.into_iter()) + ciphertext
+}
+
+/// AES decrypt using cipher block chaining (CBC) mode with an all-zero initialization vector.
 pub fn decrypt(ciphertext: ByteSlice, key: &Key) -> Result<ByteSlice<'static>, CryptopalsError> {
+    decrypt_with_iv(ciphertext, key, &Block::from(ByteArray::with_repeated_byte(0)))
+}
+
+/// AES decrypt using cipher block chaining (CBC) mode with the given initialization vector.
+pub fn decrypt_with_iv(ciphertext: ByteSlice, key: &Key, iv: &Block) -> Result<ByteSlice<'static>, CryptopalsError> {
     let mut blocks = ciphertext
         // Split into statically sized chunks
         .blocks()?
@@ -85,8 +143,8 @@ pub fn decrypt(ciphertext: ByteSlice, key: &Key) -> Result<ByteSlice<'static>, C
 
         // Initialization vector ...
         let iv = match index {
-            // ... from all zeroes
-            0 => &Block::from(ByteArray::with_repeated_byte(0)),
+            // ... from the given IV
+            0 => iv,
             // ... from previous round
             _ => left
                 .get(index - 1)
@@ -113,3 +171,18 @@ pub fn decrypt(ciphertext: ByteSlice, key: &Key) -> Result<ByteSlice<'static>, C
 
     Ok(plaintext)
 }
+
+/// AES decrypt using cipher block chaining (CBC) mode, where the first block of the ciphertext is the initialization
+/// vector.
+pub fn decrypt_with_prepended_iv(ciphertext: ByteSlice, key: &Key) -> Result<ByteSlice<'static>, CryptopalsError> {
+    // The ciphertext should at least contain the IV
+    ensure!(ciphertext.length() >= BLOCK_LENGTH, CryptopalsError::InvalidLength);
+
+    // Split the IV from the rest of the ciphertext
+    let mut bytes = ciphertext.into_iter();
+    let iv = Block::from(ByteArray::from_iter(bytes.by_ref().take(BLOCK_LENGTH)));
+    let ciphertext = ByteSlice::from_iter(bytes);
+
+    // Decrypt using the IV
+    decrypt_with_iv(ciphertext, key, &iv)
+}
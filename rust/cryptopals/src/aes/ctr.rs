@@ -0,0 +1,131 @@
+//! AES encryption using counter (CTR) mode
+//!
+//! The counter block consists of a 64-bit nonce followed by a 64-bit block counter, both in little-endian byte order.
+//! The block cipher turns the counter blocks into a keystream, which is XOR-ed with the plaintext. Therefore, no
+//! padding is needed and the ciphertext has the same length as the plaintext.
+//!
+//! ## Examples
+//! ```
+//! use cryptopals::{aes, byte::*};
+//!
+//! let key = aes::Key::from(*b"YELLOW SUBMARINE");
+//! let nonce = 0;
+//! let plaintext = ByteSlice::from("https://cryptopals.com/");
+//!
+//! let ciphertext = aes::ctr::encrypt(plaintext.clone(), &key, nonce);
+//! assert_eq!(ciphertext.length(), plaintext.length());
+//!
+//! assert_eq!(aes::ctr::decrypt(ciphertext, &key, nonce), plaintext);
+//! ```
+use super::{Block, Key, BLOCK_LENGTH};
+use crate::byte::*;
+
+/// Generator of the AES CTR keystream for a given key and nonce.
+///
+/// ## Examples
+/// ```
+/// use cryptopals::{aes, byte::*};
+///
+/// let key = aes::Key::from(*b"YELLOW SUBMARINE");
+/// let keystream = aes::ctr::Keystream::new(&key, 0).take(40).collect::<Vec<_>>();
+///
+/// // Seek to an arbitrary position in the keystream
+/// let mut seeked = aes::ctr::Keystream::new(&key, 0);
+/// seeked.seek(21);
+///
+/// assert_eq!(seeked.take(19).collect::<Vec<_>>(), keystream[21..]);
+/// ```
+pub struct Keystream<'a> {
+    key: &'a Key,
+    nonce: u64,
+    counter: u64,
+    block: Block,
+    position: usize,
+}
+
+impl<'a> Keystream<'a> {
+    /// Create a keystream starting at offset 0
+    pub fn new(key: &'a Key, nonce: u64) -> Keystream<'a> {
+        let mut keystream = Keystream {
+            key,
+            nonce,
+            counter: 0,
+            block: Block::from([0; BLOCK_LENGTH]),
+            position: 0,
+        };
+
+        // Generate the first block of the keystream
+        keystream.generate_block();
+
+        keystream
+    }
+
+    /// Move to an arbitrary byte offset in the keystream
+    pub fn seek(&mut self, offset: usize) {
+        // Only generate a new block if the offset lies in a different block
+        let counter = (offset / BLOCK_LENGTH) as u64;
+        if counter != self.counter {
+            self.counter = counter;
+            self.generate_block();
+        }
+
+        self.position = offset % BLOCK_LENGTH;
+    }
+
+    fn generate_block(&mut self) {
+        // Build counter block from nonce and counter
+        let mut bytes = [0; BLOCK_LENGTH];
+        bytes[..8].copy_from_slice(&self.nonce.to_le_bytes());
+        bytes[8..].copy_from_slice(&self.counter.to_le_bytes());
+
+        // Encrypt the counter block to get the next block of the keystream
+        let mut block = Block::from(bytes);
+        block.encrypt(self.key);
+
+        self.block = block;
+    }
+}
+
+impl Iterator for Keystream<'_> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Continue with the next block once the current one is used up
+        if self.position == BLOCK_LENGTH {
+            self.counter = self.counter.wrapping_add(1);
+            self.position = 0;
+            self.generate_block();
+        }
+
+        let byte = self.block[self.position];
+        self.position += 1;
+
+        Some(byte)
+    }
+}
+
+/// AES encrypt using counter (CTR) mode.
+pub fn encrypt(plaintext: ByteSlice, key: &Key, nonce: u64) -> ByteSlice<'static> {
+    encrypt_at_offset(plaintext, key, nonce, 0)
+}
+
+/// AES encrypt using counter (CTR) mode, starting at a byte offset in the keystream.
+///
+/// This makes it possible to encrypt a part of a larger ciphertext in place.
+pub fn encrypt_at_offset(plaintext: ByteSlice, key: &Key, nonce: u64, offset: usize) -> ByteSlice<'static> {
+    // Move the keystream to the desired offset
+    let mut keystream = Keystream::new(key, nonce);
+    keystream.seek(offset);
+
+    // XOR each byte with the keystream
+    let bytes = plaintext.iter().zip(keystream).map(|(lhs, rhs)| lhs ^ rhs);
+
+    ByteSlice::from_iter(bytes)
+}
+
+/// AES decrypt using counter (CTR) mode.
+///
+/// Since the keystream is XOR-ed with the input, decryption is identical to encryption.
+pub fn decrypt(ciphertext: ByteSlice, key: &Key, nonce: u64) -> ByteSlice<'static> {
+    encrypt(ciphertext, key, nonce)
+}
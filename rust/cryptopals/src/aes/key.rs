@@ -1,12 +1,19 @@
 use super::{byte_operator::sub_byte, Block, BLOCK_LENGTH};
+use crate::{byte::*, CryptopalsError};
+use error_stack::{bail, Report};
 
-/// AES uses 10 rounds for 128-bit keys, plus 1 for the initial key
-pub const NUMBER_OF_ROUNDS: usize = 11;
+/// Number of bytes in a 32-bit word of the key schedule
+const WORD_LENGTH: usize = 4;
+
+/// Number of words in a single round key
+const WORDS_PER_BLOCK: usize = BLOCK_LENGTH / WORD_LENGTH;
 
 // Round constants from Rijndael key schedule
 const ROUND_CONSTANTS: [u8; 11] = [0, 1, 2, 4, 8, 16, 32, 64, 128, 27, 54];
 
-/// An AES 128-bit encryption key.
+/// An AES encryption key of 128, 192 or 256 bits.
+///
+/// AES uses 10, 12 or 14 rounds respectively, plus 1 round key for the initial key.
 ///
 /// ## Examples
 /// ```
@@ -32,68 +39,124 @@ where
     B: Into<Block>,
 {
     fn from(value: B) -> Key {
-        Key::new(value.into())
+        let bytes = value.into().into_iter().collect::<Vec<_>>();
+
+        Key::new(&bytes)
+    }
+}
+
+impl From<[u8; 24]> for Key {
+    /// Create a 192-bit key
+    fn from(value: [u8; 24]) -> Key {
+        Key::new(&value)
+    }
+}
+
+impl From<[u8; 32]> for Key {
+    /// Create a 256-bit key
+    fn from(value: [u8; 32]) -> Key {
+        Key::new(&value)
+    }
+}
+
+impl TryFrom<ByteSlice<'_>> for Key {
+    type Error = Report<CryptopalsError>;
+
+    /// Try to create a key from a dynamically sized byte sequence.
+    ///
+    /// Only byte sequences of 16, 24 or 32 bytes are valid keys.
+    ///
+    /// ## Examples
+    /// ```
+    /// use cryptopals::{aes, byte::*};
+    ///
+    /// let key = aes::Key::try_from(ByteSlice::from("YELLOW SUBMARINE"))?;
+    /// assert_eq!(key.number_of_rounds(), 10);
+    ///
+    /// let key = aes::Key::try_from(ByteSlice::from("YELLOW SUBMARINE BICYCLE"))?;
+    /// assert_eq!(key.number_of_rounds(), 12);
+    ///
+    /// let result = aes::Key::try_from(ByteSlice::from("cryptopals"));
+    /// assert!(result.is_err());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    fn try_from(value: ByteSlice<'_>) -> Result<Self, Self::Error> {
+        let bytes = value.into_iter().collect::<Vec<_>>();
+
+        match bytes.len() {
+            16 | 24 | 32 => Ok(Key::new(&bytes)),
+            _ => bail!(CryptopalsError::InvalidLength),
+        }
     }
 }
 
 impl Key {
-    /// Create keys with random values
+    /// Create 128-bit keys with random values
     pub fn with_random_values(rng: &mut impl rand::Rng) -> Key {
         // Generate a random initial value
         let initial_value = Block::with_random_values(rng);
 
-        Key::new(initial_value)
+        Key::from(initial_value)
     }
 
     /// Key is automatically expanded to round keys
-    fn new(initial_value: Block) -> Key {
-        // Initialize with none of the rounds expanded
-        let mut round_keys = Vec::with_capacity(NUMBER_OF_ROUNDS);
-
-        // Push initial value
-        round_keys.push(initial_value);
-
-        // Iterate over the number of rounds
-        for (round_number, round_constant) in ROUND_CONSTANTS.iter().enumerate().skip(1) {
-            // Get previous round key
-            let prev = round_keys.get(round_number - 1).unwrap();
-
-            // Start with a blank slate
-            let mut next = [0; BLOCK_LENGTH];
-
-            // W[i] = W[i-4] XOR SubWord(RotWord(W[i-1]))
-            next[0] = prev[0] ^ sub_byte(prev[13]) ^ round_constant;
-            next[1] = prev[1] ^ sub_byte(prev[14]);
-            next[2] = prev[2] ^ sub_byte(prev[15]);
-            next[3] = prev[3] ^ sub_byte(prev[12]);
-
-            // W[i] = W[i-4] XOR W[i-1]
-            next[4] = prev[4] ^ next[0];
-            next[5] = prev[5] ^ next[1];
-            next[6] = prev[6] ^ next[2];
-            next[7] = prev[7] ^ next[3];
-
-            // W[i] = W[i-4] XOR W[i-1]
-            next[8] = prev[8] ^ next[4];
-            next[9] = prev[9] ^ next[5];
-            next[10] = prev[10] ^ next[6];
-            next[11] = prev[11] ^ next[7];
-
-            // W[i] = W[i-4] XOR W[i-1]
-            next[12] = prev[12] ^ next[8];
-            next[13] = prev[13] ^ next[9];
-            next[14] = prev[14] ^ next[10];
-            next[15] = prev[15] ^ next[11];
-
-            // Push next value
-            round_keys.push(Block::from(next));
+    fn new(initial_value: &[u8]) -> Key {
+        // Number of words in the initial key (4, 6 or 8)
+        let key_length = initial_value.len() / WORD_LENGTH;
+
+        // Number of rounds (10, 12 or 14), plus 1 for the initial key
+        let number_of_round_keys = key_length + 7;
+        let number_of_words = number_of_round_keys * WORDS_PER_BLOCK;
+
+        // Start with the words of the initial key
+        let mut words = initial_value
+            .chunks(WORD_LENGTH)
+            .map(|chunk| [chunk[0], chunk[1], chunk[2], chunk[3]])
+            .collect::<Vec<_>>();
+
+        // Iterate over all words that need to be expanded
+        for index in key_length..number_of_words {
+            let mut word = words[index - 1];
+
+            if index % key_length == 0 {
+                // W[i] = W[i-N] XOR SubWord(RotWord(W[i-1])) XOR RCON[i/N]
+                word = [
+                    sub_byte(word[1]) ^ ROUND_CONSTANTS[index / key_length],
+                    sub_byte(word[2]),
+                    sub_byte(word[3]),
+                    sub_byte(word[0]),
+                ];
+            } else if key_length > 6 && index % key_length == 4 {
+                // W[i] = W[i-N] XOR SubWord(W[i-1])
+                word = word.map(sub_byte);
+            }
+
+            // W[i] = W[i-N] XOR W[i-1]
+            let previous = words[index - key_length];
+            words.push([
+                previous[0] ^ word[0],
+                previous[1] ^ word[1],
+                previous[2] ^ word[2],
+                previous[3] ^ word[3],
+            ]);
         }
 
-        assert_eq!(round_keys.len(), NUMBER_OF_ROUNDS);
+        // Group every 4 words into a round key
+        let round_keys = words
+            .chunks(WORDS_PER_BLOCK)
+            .map(|chunk| Block::from(ByteArray::<BLOCK_LENGTH>::from_iter(chunk.iter().flatten().copied())))
+            .collect::<Vec<_>>();
+
+        assert_eq!(round_keys.len(), number_of_round_keys);
 
         Key(round_keys)
     }
 
+    /// Number of rounds, not counting the initial round key (10, 12 or 14)
+    pub fn number_of_rounds(&self) -> usize {
+        self.0.len() - 1
+    }
+
     /// Double ended iterator over round keys, so it's possible to iterate in reverse
     /// TODO: consider returning a type `Round` which contains the round number and round key
     /// instead of the tuple (usize, &Block)
@@ -161,7 +161,10 @@ impl<const N: usize> BitXor for ByteArray<N> {
 
     fn bitxor(self, rhs: Self) -> Self::Output {
         // Xor each byte of lhs and rhs
-        let iter = self.into_iter().zip(rhs.into_iter()).map(|(lhs, rhs)| lhs ^ rhs);
+        let iter = self
+            .into_iter()
+            .zip(rhs.into_iter())
+            .map(|(lhs, rhs)| lhs ^ rhs);
 
         ByteArray::from_iter(iter)
     }
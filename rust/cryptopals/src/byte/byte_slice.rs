@@ -3,6 +3,7 @@ use crate::{aes::BLOCK_LENGTH, CryptopalsError};
 use byte_encoding::encode_hexadecimal;
 use error_stack::{ensure, Result};
 use itermore::IterArrayChunks;
+use num_bigint::BigUint;
 use std::{borrow::Cow, fmt, ops::Add};
 
 /// A dynamically sized collection of bytes.
@@ -80,6 +81,41 @@ impl<'a> From<&'a str> for ByteSlice<'a> {
     }
 }
 
+impl From<&BigUint> for ByteSlice<'static> {
+    /// Create a `ByteSlice` from the big-endian bytes of a big integer
+    ///
+    /// ## Examples
+    /// ```
+    /// use cryptopals::byte::*;
+    /// use num_bigint::BigUint;
+    ///
+    /// let integer = BigUint::from(0x6869_u32);
+    /// let value = ByteSlice::from(&integer);
+    ///
+    /// assert_eq!(value, ByteSlice::from("hi"));
+    /// ```
+    fn from(value: &BigUint) -> Self {
+        ByteSlice::from(value.to_bytes_be())
+    }
+}
+
+impl From<&ByteSlice<'_>> for BigUint {
+    /// Interpret the bytes as a big-endian big integer
+    ///
+    /// ## Examples
+    /// ```
+    /// use cryptopals::byte::*;
+    /// use num_bigint::BigUint;
+    ///
+    /// let value = ByteSlice::from("hi");
+    ///
+    /// assert_eq!(BigUint::from(&value), BigUint::from(0x6869_u32));
+    /// ```
+    fn from(value: &ByteSlice<'_>) -> Self {
+        BigUint::from_bytes_be(&value.0)
+    }
+}
+
 impl<'a> ByteSlice<'a> {
     /// Pad additional bytes to reach desired block size.
     /// The length of the result will be a multiple of the block size.
@@ -124,7 +160,7 @@ impl<'a> ByteSlice<'a> {
         let difference = vec.pop().ok_or(CryptopalsError::InvalidLength)?;
 
         // Validate difference
-        ensure!(difference <= BLOCK_LENGTH as u8, CryptopalsError::InvalidPadding);
+        ensure!(0 < difference && difference <= BLOCK_LENGTH as u8, CryptopalsError::InvalidPadding);
 
         // Pop remaining bytes
         for _ in 1..difference {
@@ -204,7 +240,7 @@ impl<'a> ByteSlice<'a> {
         self,
     ) -> Result<impl Iterator<Item = ByteArray<N>> + 'static + use<'a, N>, CryptopalsError> {
         // The input should be divisible by the block length
-        ensure!(self.length() % N == 0, CryptopalsError::InvalidLength);
+        ensure!(self.length().is_multiple_of(N), CryptopalsError::InvalidLength);
 
         // Use the `itermore`` crate while `array_chunks` is unstable
         let iterator = self.into_iter().array_chunks().map(ByteArray::<N>::from);
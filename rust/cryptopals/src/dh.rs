@@ -0,0 +1,61 @@
+//! Finite-field Diffie-Hellman (DH) key exchange
+//!
+//! Two parties agree on a prime modulus `p` and a generator `g`, exchange their public keys and derive a shared
+//! secret. The shared secret is turned into an AES key, which is used to exchange messages using AES CBC mode.
+//!
+//! ## Examples
+//! ```
+//! use cryptopals::dh;
+//!
+//! let parameters = dh::Parameters::default();
+//! let mut rng = rand::thread_rng();
+//!
+//! let alice = dh::KeyPair::new(&parameters, &mut rng);
+//! let bob = dh::KeyPair::new(&parameters, &mut rng);
+//!
+//! // Both parties arrive at the same shared secret
+//! assert_eq!(alice.shared_secret(bob.public_key()), bob.shared_secret(alice.public_key()));
+//! ```
+pub use channel::{channel, Channel, Message};
+pub use key_pair::KeyPair;
+pub use parameters::Parameters;
+pub use party::Party;
+
+use crate::{
+    aes,
+    byte::*,
+    hash::{Hash, Sha1},
+};
+use num_bigint::BigUint;
+
+mod channel;
+mod key_pair;
+mod parameters;
+mod party;
+
+/// Derive an AES key from the first 16 bytes of the SHA-1 digest of the shared secret
+///
+/// ## Examples
+/// ```
+/// use cryptopals::{aes, dh};
+/// use num_bigint::BigUint;
+///
+/// // A shared secret of zero always results in the same key
+/// let key = dh::derive_key(&BigUint::from(0_u32));
+/// let expected = aes::Key::from(cryptopals::hex!("5ba93c9db0cff93f52b521d7420e43f6"));
+///
+/// for (lhs, rhs) in key.rounds().zip(expected.rounds()) {
+///     assert_eq!(lhs, rhs);
+/// }
+/// ```
+pub fn derive_key(shared_secret: &BigUint) -> aes::Key {
+    let digest = Sha1::digest(&shared_secret.to_bytes_be().into());
+
+    // Truncate the digest to the length of an AES key
+    let mut bytes = [0; aes::BLOCK_LENGTH];
+    for (byte, digest_byte) in bytes.iter_mut().zip(digest.iter()) {
+        *byte = *digest_byte;
+    }
+
+    aes::Key::from(bytes)
+}
@@ -0,0 +1,72 @@
+use super::Parameters;
+use crate::{byte::*, CryptopalsError};
+use error_stack::{report, Result};
+use num_bigint::BigUint;
+use std::sync::mpsc::{self, Receiver, Sender};
+
+/// A message of the key exchange protocol
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Message {
+    /// Proposed parameters, or an acknowledgement of the parameters that will be used
+    Parameters(Parameters),
+    /// Public key of the sender
+    PublicKey(BigUint),
+    /// A message encrypted with AES CBC mode, where the IV is prepended to the ciphertext
+    Ciphertext(ByteSlice<'static>),
+}
+
+/// One end of an in-memory, bidirectional channel
+pub struct Channel {
+    sender: Sender<Message>,
+    receiver: Receiver<Message>,
+}
+
+/// Create a pair of connected channels, such that messages sent on one end are received on the other
+///
+/// ## Examples
+/// ```
+/// use cryptopals::dh;
+/// use num_bigint::BigUint;
+///
+/// let (alice, bob) = dh::channel();
+///
+/// alice.send(dh::Message::PublicKey(BigUint::from(42_u32)))?;
+/// assert_eq!(bob.receive()?, dh::Message::PublicKey(BigUint::from(42_u32)));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn channel() -> (Channel, Channel) {
+    let (lhs_sender, rhs_receiver) = mpsc::channel();
+    let (rhs_sender, lhs_receiver) = mpsc::channel();
+
+    let lhs = Channel {
+        sender: lhs_sender,
+        receiver: lhs_receiver,
+    };
+    let rhs = Channel {
+        sender: rhs_sender,
+        receiver: rhs_receiver,
+    };
+
+    (lhs, rhs)
+}
+
+impl Channel {
+    /// Send a message to the other end of the channel
+    pub fn send(&self, message: Message) -> Result<(), CryptopalsError> {
+        self.sender
+            .send(message)
+            .map_err(|_| report!(CryptopalsError::ChannelClosed))
+    }
+
+    /// Wait for a message from the other end of the channel
+    pub fn receive(&self) -> Result<Message, CryptopalsError> {
+        self.receiver
+            .recv()
+            .map_err(|_| report!(CryptopalsError::ChannelClosed))
+    }
+
+    /// Split into the sending and the receiving half, so they can be moved to different threads
+    pub fn split(self) -> (Sender<Message>, Receiver<Message>) {
+        (self.sender, self.receiver)
+    }
+}
@@ -0,0 +1,35 @@
+use super::Parameters;
+use num_bigint::{BigUint, RandBigInt};
+
+/// A random private key `a` together with the public key `A = g^a mod p`
+#[derive(Debug)]
+pub struct KeyPair {
+    p: BigUint,
+    private_key: BigUint,
+    public_key: BigUint,
+}
+
+impl KeyPair {
+    /// Generate a key pair with a random private key in the range `[1, p)`
+    pub fn new(parameters: &Parameters, rng: &mut impl rand::Rng) -> KeyPair {
+        let p = parameters.p().clone();
+        let private_key = rng.gen_biguint_range(&BigUint::from(1_u32), &p);
+        let public_key = parameters.g().modpow(&private_key, &p);
+
+        KeyPair {
+            p,
+            private_key,
+            public_key,
+        }
+    }
+
+    /// Return the public key, which can be sent to the other party
+    pub fn public_key(&self) -> &BigUint {
+        &self.public_key
+    }
+
+    /// Calculate the shared secret `s = B^a mod p` from the public key of the other party
+    pub fn shared_secret(&self, public_key: &BigUint) -> BigUint {
+        public_key.modpow(&self.private_key, &self.p)
+    }
+}
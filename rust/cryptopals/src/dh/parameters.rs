@@ -0,0 +1,46 @@
+use num_bigint::BigUint;
+
+// The 1536-bit MODP group from RFC 3526, as used in challenge 33
+const NIST_PRIME: &str = "\
+    ffffffffffffffffc90fdaa22168c234c4c6628b80dc1cd129024e088a67cc74020bbea63b139b22514a08798e3404ddef9519b3cd3a431b\
+    302b0a6df25f14374fe1356d6d51c245e485b576625e7ec6f44c42e9a637ed6b0bff5cb6f406b7edee386bfb5a899fa5ae9f24117c4b1fe6\
+    49286651ece45b3dc2007cb8a163bf0598da48361c55d39a69163fa8fd24cf5f83655d23dca3ad961c62f356208552bb9ed529077096966d\
+    670c354e4abc9804f1746c08ca237327ffffffffffffffff";
+
+// Generator of the 1536-bit MODP group
+const NIST_GENERATOR: u32 = 2;
+
+/// The prime modulus `p` and generator `g` which both parties agree on.
+///
+/// The default parameters are the NIST 1536-bit MODP prime with generator 2.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Parameters {
+    p: BigUint,
+    g: BigUint,
+}
+
+impl Default for Parameters {
+    fn default() -> Self {
+        let p = BigUint::parse_bytes(NIST_PRIME.as_bytes(), 16).expect("prime should be valid hexadecimal");
+        let g = BigUint::from(NIST_GENERATOR);
+
+        Parameters { p, g }
+    }
+}
+
+impl Parameters {
+    /// Create parameters with a custom prime modulus and generator
+    pub fn new(p: BigUint, g: BigUint) -> Parameters {
+        Parameters { p, g }
+    }
+
+    /// Return the prime modulus
+    pub fn p(&self) -> &BigUint {
+        &self.p
+    }
+
+    /// Return the generator
+    pub fn g(&self) -> &BigUint {
+        &self.g
+    }
+}
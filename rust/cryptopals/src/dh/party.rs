@@ -0,0 +1,101 @@
+use super::{derive_key, Channel, KeyPair, Message, Parameters};
+use crate::{aes, byte::*, CryptopalsError};
+use error_stack::{bail, Result};
+
+/// A party that has completed the key exchange and can exchange encrypted messages over its channel.
+///
+/// The key exchange consists of the following messages:
+///  - the initiator proposes the parameters `p` and `g`
+///  - the responder acknowledges the parameters, which are then used by both parties
+///  - the initiator sends its public key `A`
+///  - the responder sends its public key `B`
+///
+/// Afterwards, both parties derive the same AES key from the shared secret.
+///
+/// ## Examples
+/// ```
+/// use cryptopals::{byte::*, dh};
+/// use std::thread;
+///
+/// let (alice, bob) = dh::channel();
+///
+/// // Bob echoes a single message in the background
+/// let bob = thread::spawn(move || -> Result<(), error_stack::Report<cryptopals::CryptopalsError>> {
+///     let bob = dh::Party::respond(bob)?;
+///     bob.send(bob.receive()?)
+/// });
+///
+/// let alice = dh::Party::initiate(alice, dh::Parameters::default())?;
+/// alice.send(ByteSlice::from("Hi Bob!"))?;
+///
+/// assert_eq!(alice.receive()?, ByteSlice::from("Hi Bob!"));
+/// assert!(bob.join().unwrap().is_ok());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct Party {
+    channel: Channel,
+    key: aes::Key,
+}
+
+impl Party {
+    /// Start the key exchange by proposing parameters
+    pub fn initiate(channel: Channel, parameters: Parameters) -> Result<Party, CryptopalsError> {
+        let mut rng = rand::thread_rng();
+
+        // Propose parameters and use the ones that are acknowledged
+        channel.send(Message::Parameters(parameters))?;
+        let Message::Parameters(parameters) = channel.receive()? else {
+            bail!(CryptopalsError::UnexpectedMessage);
+        };
+
+        // Exchange public keys
+        let key_pair = KeyPair::new(&parameters, &mut rng);
+        channel.send(Message::PublicKey(key_pair.public_key().clone()))?;
+        let Message::PublicKey(public_key) = channel.receive()? else {
+            bail!(CryptopalsError::UnexpectedMessage);
+        };
+
+        let key = derive_key(&key_pair.shared_secret(&public_key));
+
+        Ok(Party { channel, key })
+    }
+
+    /// Wait for the other party to start the key exchange
+    pub fn respond(channel: Channel) -> Result<Party, CryptopalsError> {
+        let mut rng = rand::thread_rng();
+
+        // Acknowledge the proposed parameters
+        let Message::Parameters(parameters) = channel.receive()? else {
+            bail!(CryptopalsError::UnexpectedMessage);
+        };
+        channel.send(Message::Parameters(parameters.clone()))?;
+
+        // Exchange public keys
+        let Message::PublicKey(public_key) = channel.receive()? else {
+            bail!(CryptopalsError::UnexpectedMessage);
+        };
+        let key_pair = KeyPair::new(&parameters, &mut rng);
+        channel.send(Message::PublicKey(key_pair.public_key().clone()))?;
+
+        let key = derive_key(&key_pair.shared_secret(&public_key));
+
+        Ok(Party { channel, key })
+    }
+
+    /// Encrypt a message with a random IV and send it to the other party
+    pub fn send(&self, plaintext: ByteSlice<'_>) -> Result<(), CryptopalsError> {
+        let mut rng = rand::thread_rng();
+        let ciphertext = aes::cbc::encrypt_with_random_iv(plaintext, &self.key, &mut rng);
+
+        self.channel.send(Message::Ciphertext(ciphertext))
+    }
+
+    /// Wait for an encrypted message from the other party and decrypt it
+    pub fn receive(&self) -> Result<ByteSlice<'static>, CryptopalsError> {
+        let Message::Ciphertext(ciphertext) = self.channel.receive()? else {
+            bail!(CryptopalsError::UnexpectedMessage);
+        };
+
+        aes::cbc::decrypt_with_prepended_iv(ciphertext, &self.key)
+    }
+}
@@ -0,0 +1,35 @@
+//! Digital Signature Algorithm (DSA)
+//!
+//! The signer and verifier agree on domain parameters `p`, `q` and `g`. Messages are hashed with SHA-1, and the digest
+//! is interpreted as a big-endian big integer.
+//!
+//! ## Examples
+//! ```
+//! use cryptopals::{byte::*, dsa};
+//!
+//! let mut rng = rand::thread_rng();
+//! let private_key = dsa::PrivateKey::generate(dsa::Parameters::default(), &mut rng);
+//!
+//! let message = ByteSlice::from("cryptopals");
+//! let signature = private_key.sign(&message, &mut rng);
+//!
+//! assert!(private_key.public_key().verify(&message, &signature));
+//! ```
+pub use key::{PrivateKey, PublicKey};
+pub use parameters::Parameters;
+pub use signature::Signature;
+
+use crate::{
+    byte::*,
+    hash::{Hash, Sha1},
+};
+use num_bigint::BigUint;
+
+mod key;
+mod parameters;
+mod signature;
+
+/// Hash a message with SHA-1 and interpret the digest as a big integer
+pub fn hash_message(message: &ByteSlice<'_>) -> BigUint {
+    BigUint::from(&Sha1::digest(message))
+}
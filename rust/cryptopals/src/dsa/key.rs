@@ -0,0 +1,112 @@
+use super::{hash_message, Parameters, Signature};
+use crate::{byte::*, number_theory::modular_inverse};
+use num_bigint::{BigUint, RandBigInt};
+use num_traits::Zero;
+
+/// The public key `y = g^x mod p` together with the domain parameters
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PublicKey {
+    parameters: Parameters,
+    y: BigUint,
+}
+
+impl PublicKey {
+    /// Create a public key from domain parameters and `y`
+    pub fn new(parameters: Parameters, y: BigUint) -> PublicKey {
+        PublicKey { parameters, y }
+    }
+
+    /// Return the domain parameters
+    pub fn parameters(&self) -> &Parameters {
+        &self.parameters
+    }
+
+    /// Return `y`
+    pub fn y(&self) -> &BigUint {
+        &self.y
+    }
+
+    /// Verify the signature of a message, which requires that both `r` and `s` lie in the range `(0, q)`
+    pub fn verify(&self, message: &ByteSlice<'_>, signature: &Signature) -> bool {
+        let q = self.parameters.q();
+        let is_in_range = |value: &BigUint| !value.is_zero() && value < q;
+
+        is_in_range(signature.r()) && is_in_range(signature.s()) && self.verify_unchecked(message, signature)
+    }
+
+    /// Verify the signature of a message the wrong way, without checking whether `r` and `s` lie in the range `(0, q)`
+    pub fn verify_unchecked(&self, message: &ByteSlice<'_>, signature: &Signature) -> bool {
+        let (p, q, g) = (self.parameters.p(), self.parameters.q(), self.parameters.g());
+
+        let Ok(w) = modular_inverse(signature.s(), q) else {
+            return false;
+        };
+        let u1 = hash_message(message) * &w % q;
+        let u2 = signature.r() * &w % q;
+
+        // v = (g^u1 * y^u2 mod p) mod q
+        let v = g.modpow(&u1, p) * self.y.modpow(&u2, p) % p % q;
+
+        v == *signature.r()
+    }
+}
+
+/// A DSA key pair, consisting of the private key `x` and the public key `y = g^x mod p`
+#[derive(Clone, Debug)]
+pub struct PrivateKey {
+    public_key: PublicKey,
+    x: BigUint,
+}
+
+impl PrivateKey {
+    /// Create a key pair from domain parameters and the private key `x`
+    pub fn new(parameters: Parameters, x: BigUint) -> PrivateKey {
+        let y = parameters.g().modpow(&x, parameters.p());
+        let public_key = PublicKey::new(parameters, y);
+
+        PrivateKey { public_key, x }
+    }
+
+    /// Generate a key pair with a random private key in the range `[1, q)`
+    pub fn generate(parameters: Parameters, rng: &mut impl rand::Rng) -> PrivateKey {
+        let x = rng.gen_biguint_range(&BigUint::from(1_u32), parameters.q());
+
+        PrivateKey::new(parameters, x)
+    }
+
+    /// Return the public part of the key pair
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+
+    /// Return the private key `x`
+    pub fn x(&self) -> &BigUint {
+        &self.x
+    }
+
+    /// Sign a message with a random nonce `k` in the range `[1, q)`
+    pub fn sign(&self, message: &ByteSlice<'_>, rng: &mut impl rand::Rng) -> Signature {
+        let k = rng.gen_biguint_range(&BigUint::from(1_u32), self.public_key.parameters.q());
+
+        self.sign_with_nonce(message, &k)
+    }
+
+    /// Sign a message with the given nonce `k`.
+    ///
+    /// The nonce should be secret, random and never reused, otherwise the private key can be recovered from the
+    /// signature. This function does not reject a signature where `r` or `s` is zero, so tampered parameters can be
+    /// demonstrated.
+    pub fn sign_with_nonce(&self, message: &ByteSlice<'_>, k: &BigUint) -> Signature {
+        let parameters = &self.public_key.parameters;
+        let (p, q, g) = (parameters.p(), parameters.q(), parameters.g());
+
+        // r = (g^k mod p) mod q
+        let r = g.modpow(k, p) % q;
+
+        // s = k^-1 (H(m) + x * r) mod q, where the nonce is invertible since q is prime
+        let k_inverse = modular_inverse(k, q).expect("nonce should be in the range [1, q)");
+        let s = k_inverse * (hash_message(message) + &self.x * &r) % q;
+
+        Signature::new(r, s)
+    }
+}
@@ -0,0 +1,54 @@
+use num_bigint::BigUint;
+
+// Domain parameters as used in challenge 43
+const DEFAULT_P: &str = "\
+    800000000000000089e1855218a0e7dac38136ffafa72eda7859f2171e25e65eac698c1702578b07dc2a1076da241c76c62d374d8389ea5aeff\
+    d3226a0530cc565f3bf6b50929139ebeac04f48c3c84afb796d61e5a4f9a8fda812ab59494232c7d2b4deb50aa18ee9e132bfa85ac4374d7f9\
+    091abc3d015efc871a584471bb1";
+const DEFAULT_Q: &str = "f4f47f05794b256174bba6e9b396a7707e563c5b";
+const DEFAULT_G: &str = "\
+    5958c9d3898b224b12672c0b98e06c60df923cb8bc999d119458fef538b8fa4046c8db53039db620c094c9fa077ef389b5322a559946a7190\
+    3f990f1f7e0e025e2d7f7cf494aff1a0470f5b64c36b625a097f1651fe775323556fe00b3608c887892878480e99041be601a62166ca6894bd\
+    d41a7054ec89f756ba9fc95302291";
+
+/// The prime modulus `p`, the prime order `q` of the subgroup and the generator `g` of the subgroup, which the signer
+/// and verifier agree on.
+///
+/// The default parameters have a 1024-bit `p` and a 160-bit `q`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Parameters {
+    p: BigUint,
+    q: BigUint,
+    g: BigUint,
+}
+
+impl Default for Parameters {
+    fn default() -> Self {
+        let parse =
+            |value: &str| BigUint::parse_bytes(value.as_bytes(), 16).expect("parameter should be valid hexadecimal");
+
+        Parameters::new(parse(DEFAULT_P), parse(DEFAULT_Q), parse(DEFAULT_G))
+    }
+}
+
+impl Parameters {
+    /// Create parameters with a custom modulus, order and generator
+    pub fn new(p: BigUint, q: BigUint, g: BigUint) -> Parameters {
+        Parameters { p, q, g }
+    }
+
+    /// Return the prime modulus
+    pub fn p(&self) -> &BigUint {
+        &self.p
+    }
+
+    /// Return the prime order of the subgroup
+    pub fn q(&self) -> &BigUint {
+        &self.q
+    }
+
+    /// Return the generator of the subgroup
+    pub fn g(&self) -> &BigUint {
+        &self.g
+    }
+}
@@ -0,0 +1,25 @@
+use num_bigint::BigUint;
+
+/// A DSA signature, consisting of `r` and `s`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Signature {
+    r: BigUint,
+    s: BigUint,
+}
+
+impl Signature {
+    /// Create a signature from its two components
+    pub fn new(r: BigUint, s: BigUint) -> Signature {
+        Signature { r, s }
+    }
+
+    /// Return the first component `r = (g^k mod p) mod q`
+    pub fn r(&self) -> &BigUint {
+        &self.r
+    }
+
+    /// Return the second component `s = k^-1 (H(m) + x * r) mod q`
+    pub fn s(&self) -> &BigUint {
+        &self.s
+    }
+}
@@ -18,4 +18,13 @@ pub enum CryptopalsError {
     /// The input does not have valid padding
     #[error("The input does not have valid padding")]
     InvalidPadding,
+    /// The other end of the channel has been closed
+    #[error("The other end of the channel has been closed")]
+    ChannelClosed,
+    /// A different message was expected
+    #[error("A different message was expected")]
+    UnexpectedMessage,
+    /// The input does not have a modular inverse
+    #[error("The input does not have a modular inverse")]
+    NoModularInverse,
 }
@@ -0,0 +1,66 @@
+//! Cryptographic hash functions
+//!
+//! ## Examples
+//! ```
+//! use cryptopals::{byte::*, hash::{Hash, Sha1}};
+//!
+//! let message = ByteSlice::from("cryptopals");
+//! let digest = Sha1::digest(&message);
+//!
+//! assert_eq!(digest.length(), Sha1::DIGEST_LENGTH);
+//! ```
+pub use hmac::hmac;
+pub use md4::Md4;
+pub use sha1::Sha1;
+pub use sha256::Sha256;
+
+use crate::{byte::*, CryptopalsError};
+use error_stack::Result;
+
+mod hmac;
+mod md4;
+mod merkle_damgard;
+mod sha1;
+mod sha256;
+
+/// Trait that should be implemented by all hash functions.
+///
+/// This allows oracles to accept any hash function as a type parameter.
+pub trait Hash: Default {
+    /// Number of bytes in the digest
+    const DIGEST_LENGTH: usize;
+
+    /// Number of bytes in a single block of the message
+    const BLOCK_LENGTH: usize;
+
+    /// Process additional bytes of the message
+    fn update(&mut self, message: &ByteSlice<'_>);
+
+    /// Pad the message and return the digest
+    fn finalize(self) -> ByteSlice<'static>;
+
+    /// Return the padding that is appended to a message of the given length
+    ///
+    /// ## Examples
+    /// ```
+    /// use cryptopals::{byte::*, hash::{Hash, Md4, Sha1}};
+    ///
+    /// // SHA-1 encodes the length in bits as big-endian, while MD4 uses little-endian
+    /// assert_eq!(Sha1::padding(3).length(), 61);
+    /// assert_eq!(Sha1::padding(3).get(60), Some(&24));
+    /// assert_eq!(Md4::padding(3).get(53), Some(&24));
+    /// ```
+    fn padding(message_length: usize) -> ByteSlice<'static>;
+
+    /// Resume hashing from a digest, after `length` bytes (including padding) have been processed
+    ///
+    /// This is possible because the digest is the entire internal state of the hash function.
+    fn from_digest(digest: &ByteSlice<'_>, length: usize) -> Result<Self, CryptopalsError>;
+
+    /// Return the digest of a complete message
+    fn digest(message: &ByteSlice<'_>) -> ByteSlice<'static> {
+        let mut hash = Self::default();
+        hash.update(message);
+        hash.finalize()
+    }
+}
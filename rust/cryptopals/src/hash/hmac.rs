@@ -0,0 +1,34 @@
+use super::Hash;
+use crate::byte::*;
+
+// Padding bytes as defined in RFC 2104
+const INNER_PADDING: u8 = 0x36;
+const OUTER_PADDING: u8 = 0x5c;
+
+/// Keyed-hash message authentication code (HMAC) using any hash function
+///
+/// ## Examples
+/// ```
+/// use cryptopals::{byte::*, hash::{hmac, Sha1}};
+/// use byte_encoding_macro::hex;
+///
+/// let key = ByteSlice::from("key");
+/// let message = ByteSlice::from("The quick brown fox jumps over the lazy dog");
+/// let expected = ByteSlice::from(hex!("de7c9b85b8b78aa6bc8a7a36f70a90701c9db4d9").as_ref());
+///
+/// assert_eq!(hmac::<Sha1>(&key, &message), expected);
+/// ```
+pub fn hmac<H: Hash>(key: &ByteSlice<'_>, message: &ByteSlice<'_>) -> ByteSlice<'static> {
+    // Keys longer than the block length are hashed first
+    let key = match key.length() > H::BLOCK_LENGTH {
+        true => H::digest(key),
+        false => ByteSlice::from_iter(key.iter().copied()),
+    };
+
+    // Keys shorter than the block length are padded with zeroes
+    let key = &key + ByteSlice::with_repeated_byte_and_length(H::BLOCK_LENGTH - key.length(), 0);
+
+    // H((K ^ opad) || H((K ^ ipad) || message))
+    let inner_digest = H::digest(&(key.single_byte_xor(INNER_PADDING) + message));
+    H::digest(&(key.single_byte_xor(OUTER_PADDING) + inner_digest))
+}
@@ -0,0 +1,131 @@
+use super::{
+    merkle_damgard::{self, ByteOrder, MerkleDamgard, CHUNK_LENGTH},
+    Hash,
+};
+use crate::{byte::*, CryptopalsError};
+use error_stack::Result;
+
+// Byte order of the state and the length field
+const BYTE_ORDER: ByteOrder = ByteOrder::LittleEndian;
+
+// Initial state as defined in RFC 1320
+const INITIAL_STATE: [u32; 4] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476];
+
+// Order in which the words of a chunk are used in each round
+const WORD_ORDER: [[usize; 16]; 3] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [0, 4, 8, 12, 1, 5, 9, 13, 2, 6, 10, 14, 3, 7, 11, 15],
+    [0, 8, 4, 12, 2, 10, 6, 14, 1, 9, 5, 13, 3, 11, 7, 15],
+];
+
+// Left rotation amounts of each round
+const ROTATIONS: [[u32; 4]; 3] = [[3, 7, 11, 19], [3, 5, 9, 13], [3, 9, 11, 15]];
+
+// Additive constants of each round
+const ROUND_CONSTANTS: [u32; 3] = [0, 0x5a827999, 0x6ed9eba1];
+
+/// The MD4 message digest algorithm
+///
+/// The digest is the entire internal state, so it is possible to resume hashing from a digest.
+///
+/// ## Examples
+/// ```
+/// use cryptopals::{byte::*, hash::{Hash, Md4}};
+/// use byte_encoding_macro::hex;
+///
+/// let message = ByteSlice::from("abc");
+/// let expected = ByteSlice::from(hex!("a448017aaf21d8525fc10ae87aa6729d").as_ref());
+///
+/// assert_eq!(Md4::digest(&message), expected);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Md4(MerkleDamgard<4>);
+
+impl Default for Md4 {
+    fn default() -> Self {
+        Md4(MerkleDamgard::new(INITIAL_STATE, 0))
+    }
+}
+
+// Compress a single chunk of 64 bytes into the state
+fn compress(state: &mut [u32; 4], chunk: &[u8]) {
+    let mut words = [0u32; 16];
+    for (word, bytes) in words.iter_mut().zip(chunk.chunks(4)) {
+        *word = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    }
+
+    // Each step updates one of the registers, after which the registers are rotated
+    let mut registers = *state;
+
+    for round in 0..3 {
+        for step in 0..16 {
+            let [a, b, c, d] = registers;
+
+            // Auxiliary function of the round
+            let f = match round {
+                0 => (b & c) | (!b & d),
+                1 => (b & c) | (b & d) | (c & d),
+                _ => b ^ c ^ d,
+            };
+
+            let value = a
+                .wrapping_add(f)
+                .wrapping_add(words[WORD_ORDER[round][step]])
+                .wrapping_add(ROUND_CONSTANTS[round])
+                .rotate_left(ROTATIONS[round][step % 4]);
+
+            registers = [d, value, b, c];
+        }
+    }
+
+    // Add the compressed chunk to the current state
+    for (value, addition) in state.iter_mut().zip(registers) {
+        *value = value.wrapping_add(addition);
+    }
+}
+
+impl Hash for Md4 {
+    const DIGEST_LENGTH: usize = 16;
+    const BLOCK_LENGTH: usize = CHUNK_LENGTH;
+
+    fn update(&mut self, message: &ByteSlice<'_>) {
+        self.0.update(message, compress);
+    }
+
+    fn finalize(self) -> ByteSlice<'static> {
+        self.0.finalize(compress, BYTE_ORDER)
+    }
+
+    fn padding(message_length: usize) -> ByteSlice<'static> {
+        merkle_damgard::padding(message_length, BYTE_ORDER)
+    }
+
+    fn from_digest(digest: &ByteSlice<'_>, length: usize) -> Result<Self, CryptopalsError> {
+        MerkleDamgard::from_digest(digest, length, BYTE_ORDER).map(Md4)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use byte_encoding_macro::hex;
+
+    // Test suite from RFC 1320
+    #[test]
+    fn rfc_1320_test_suite() {
+        let examples = [
+            ("", hex!("31d6cfe0d16ae931b73c59d7e0c089c0")),
+            ("a", hex!("bde52cb31de33e46245e05fbdbd6fb24")),
+            ("message digest", hex!("d9130a8164549fe818874806e1c7014b")),
+            ("abcdefghijklmnopqrstuvwxyz", hex!("d79e1c308aa5bbcdeea8ed63df412da9")),
+            (
+                "12345678901234567890123456789012345678901234567890123456789012345678901234567890",
+                hex!("e33b4ddc9c38f2199c3e7b164fcc0536"),
+            ),
+        ];
+
+        for (message, expected) in examples {
+            assert_eq!(Md4::digest(&ByteSlice::from(message)), ByteSlice::from(expected.as_ref()));
+        }
+    }
+}
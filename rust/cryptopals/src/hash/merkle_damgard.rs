@@ -0,0 +1,131 @@
+use crate::{byte::*, CryptopalsError};
+use error_stack::{ensure, Result};
+
+/// Number of bytes in a single chunk of the message
+pub const CHUNK_LENGTH: usize = 64;
+
+/// Number of bytes used to encode the message length in the padding
+const LENGTH_FIELD_LENGTH: usize = 8;
+
+/// Byte order of the words of the state and the length field of the padding
+#[derive(Clone, Copy, Debug)]
+pub enum ByteOrder {
+    BigEndian,
+    LittleEndian,
+}
+
+/// Buffering and padding shared by hash functions that use the Merkle–Damgård construction.
+///
+/// The message is split into chunks of 64 bytes, which are compressed into a state of `N` 32-bit words. The digest is
+/// the entire state, which allows resuming hashing from a digest.
+#[derive(Clone, Debug)]
+pub struct MerkleDamgard<const N: usize> {
+    state: [u32; N],
+    length: usize,
+    buffer: Vec<u8>,
+}
+
+impl<const N: usize> MerkleDamgard<N> {
+    /// Resume hashing from a given state, after `length` bytes have been processed.
+    pub fn new(state: [u32; N], length: usize) -> Self {
+        MerkleDamgard {
+            state,
+            length,
+            buffer: Vec::with_capacity(CHUNK_LENGTH),
+        }
+    }
+
+    /// Resume hashing from a digest, after `length` bytes have been processed.
+    pub fn from_digest(digest: &ByteSlice<'_>, length: usize, byte_order: ByteOrder) -> Result<Self, CryptopalsError> {
+        ensure!(digest.length() == 4 * N, CryptopalsError::InvalidLength);
+
+        // Split the digest into the words of the state
+        let bytes = digest.iter().copied().collect::<Vec<_>>();
+        let mut state = [0; N];
+        for (word, bytes) in state.iter_mut().zip(bytes.chunks(4)) {
+            let bytes = [bytes[0], bytes[1], bytes[2], bytes[3]];
+            *word = match byte_order {
+                ByteOrder::BigEndian => u32::from_be_bytes(bytes),
+                ByteOrder::LittleEndian => u32::from_le_bytes(bytes),
+            };
+        }
+
+        Ok(MerkleDamgard::new(state, length))
+    }
+
+    /// Process additional bytes of the message, compressing each complete chunk into the state
+    pub fn update(&mut self, message: &ByteSlice<'_>, compress: impl Fn(&mut [u32; N], &[u8])) {
+        self.length += message.length();
+        self.buffer.extend(message.iter());
+
+        // Process all complete chunks
+        let number_of_chunks = self.buffer.len() / CHUNK_LENGTH;
+        let buffer = self
+            .buffer
+            .drain(..number_of_chunks * CHUNK_LENGTH)
+            .collect::<Vec<_>>();
+        for chunk in buffer.chunks(CHUNK_LENGTH) {
+            compress(&mut self.state, chunk);
+        }
+    }
+
+    /// Pad the message and return the state as digest
+    pub fn finalize(mut self, compress: impl Fn(&mut [u32; N], &[u8]), byte_order: ByteOrder) -> ByteSlice<'static> {
+        // Pad the message to a multiple of the chunk length
+        let padding = padding(self.length, byte_order);
+        self.update(&padding, compress);
+
+        let bytes = self.state.into_iter().flat_map(|word| match byte_order {
+            ByteOrder::BigEndian => word.to_be_bytes(),
+            ByteOrder::LittleEndian => word.to_le_bytes(),
+        });
+
+        ByteSlice::from_iter(bytes)
+    }
+}
+
+/// Return the padding that is appended to a message of the given length
+pub fn padding(message_length: usize, byte_order: ByteOrder) -> ByteSlice<'static> {
+    // A single bit followed by zeroes, such that the length field ends exactly at a chunk boundary
+    let zeroes_length = (2 * CHUNK_LENGTH - LENGTH_FIELD_LENGTH - 1 - message_length % CHUNK_LENGTH) % CHUNK_LENGTH;
+
+    // Message length in bits
+    let length_field = match byte_order {
+        ByteOrder::BigEndian => ((message_length as u64) * 8).to_be_bytes(),
+        ByteOrder::LittleEndian => ((message_length as u64) * 8).to_le_bytes(),
+    };
+
+    let bytes = [0x80]
+        .into_iter()
+        .chain((0..zeroes_length).map(|_| 0))
+        .chain(length_field);
+
+    ByteSlice::from_iter(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn padding_ends_at_chunk_boundary() {
+        for message_length in [0, 1, 55, 56, 63, 64, 119, 120] {
+            let padding = padding(message_length, ByteOrder::BigEndian);
+
+            assert_eq!((message_length + padding.length()) % CHUNK_LENGTH, 0);
+            assert!(padding.length() > LENGTH_FIELD_LENGTH);
+        }
+    }
+
+    #[test]
+    fn digest_round_trip() {
+        let state = [0x01234567, 0x89abcdef, 0xfedcba98, 0x76543210];
+
+        for byte_order in [ByteOrder::BigEndian, ByteOrder::LittleEndian] {
+            let digest = MerkleDamgard::new(state, 0).finalize(|_, _| {}, byte_order);
+            let hash = MerkleDamgard::<4>::from_digest(&digest, CHUNK_LENGTH, byte_order).unwrap();
+
+            assert_eq!(hash.state, state);
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use super::{
+    merkle_damgard::{self, ByteOrder, MerkleDamgard, CHUNK_LENGTH},
+    Hash,
+};
+use crate::{byte::*, CryptopalsError};
+use error_stack::Result;
+
+// Byte order of the state and the length field
+const BYTE_ORDER: ByteOrder = ByteOrder::BigEndian;
+
+// Initial state as defined in FIPS 180-4
+const INITIAL_STATE: [u32; 5] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0];
+
+/// The Secure Hash Algorithm 1 (SHA-1)
+///
+/// The digest is the entire internal state, so it is possible to resume hashing from a digest.
+///
+/// ## Examples
+/// ```
+/// use cryptopals::{byte::*, hash::{Hash, Sha1}};
+/// use byte_encoding_macro::hex;
+///
+/// let message = ByteSlice::from("abc");
+/// let expected = ByteSlice::from(hex!("a9993e364706816aba3e25717850c26c9cd0d89d").as_ref());
+///
+/// assert_eq!(Sha1::digest(&message), expected);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Sha1(MerkleDamgard<5>);
+
+impl Default for Sha1 {
+    fn default() -> Self {
+        Sha1(MerkleDamgard::new(INITIAL_STATE, 0))
+    }
+}
+
+// Compress a single chunk of 64 bytes into the state
+fn compress(state: &mut [u32; 5], chunk: &[u8]) {
+    // Message schedule
+    let mut words = [0u32; 80];
+    for (word, bytes) in words.iter_mut().zip(chunk.chunks(4)) {
+        *word = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    }
+    for index in 16..80 {
+        words[index] = (words[index - 3] ^ words[index - 8] ^ words[index - 14] ^ words[index - 16]).rotate_left(1);
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e] = *state;
+
+    for (index, word) in words.into_iter().enumerate() {
+        // Round function and constant depend on the round
+        let (f, k) = match index {
+            0..=19 => ((b & c) | (!b & d), 0x5a827999),
+            20..=39 => (b ^ c ^ d, 0x6ed9eba1),
+            40..=59 => ((b & c) | (b & d) | (c & d), 0x8f1bbcdc),
+            _ => (b ^ c ^ d, 0xca62c1d6),
+        };
+
+        let temp = a
+            .rotate_left(5)
+            .wrapping_add(f)
+            .wrapping_add(e)
+            .wrapping_add(k)
+            .wrapping_add(word);
+
+        e = d;
+        d = c;
+        c = b.rotate_left(30);
+        b = a;
+        a = temp;
+    }
+
+    // Add the compressed chunk to the current state
+    for (value, addition) in state.iter_mut().zip([a, b, c, d, e]) {
+        *value = value.wrapping_add(addition);
+    }
+}
+
+impl Hash for Sha1 {
+    const DIGEST_LENGTH: usize = 20;
+    const BLOCK_LENGTH: usize = CHUNK_LENGTH;
+
+    fn update(&mut self, message: &ByteSlice<'_>) {
+        self.0.update(message, compress);
+    }
+
+    fn finalize(self) -> ByteSlice<'static> {
+        self.0.finalize(compress, BYTE_ORDER)
+    }
+
+    fn padding(message_length: usize) -> ByteSlice<'static> {
+        merkle_damgard::padding(message_length, BYTE_ORDER)
+    }
+
+    fn from_digest(digest: &ByteSlice<'_>, length: usize) -> Result<Self, CryptopalsError> {
+        MerkleDamgard::from_digest(digest, length, BYTE_ORDER).map(Sha1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use byte_encoding_macro::hex;
+
+    #[test]
+    fn empty_message() {
+        let digest = Sha1::digest(&ByteSlice::from(""));
+        let expected = hex!("da39a3ee5e6b4b0d3255bfef95601890afd80709");
+
+        assert_eq!(digest, ByteSlice::from(expected.as_ref()));
+    }
+
+    #[test]
+    fn multiple_chunks() {
+        let message = ByteSlice::from("abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq");
+        let expected = hex!("84983e441c3bd26ebaae4aa1f95129e5e54670f1");
+
+        assert_eq!(Sha1::digest(&message), ByteSlice::from(expected.as_ref()));
+    }
+
+    #[test]
+    fn incremental_updates() {
+        let message = ByteSlice::from("The quick brown fox jumps over the lazy dog");
+        let expected = hex!("2fd4e1c67a2d28fced849ee1bb76e7391b93eb12");
+
+        // Feed the message in parts
+        let mut hash = Sha1::default();
+        for chunk in message.chunks(7) {
+            hash.update(&chunk);
+        }
+
+        assert_eq!(hash.finalize(), ByteSlice::from(expected.as_ref()));
+    }
+}
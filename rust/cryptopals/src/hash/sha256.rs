@@ -0,0 +1,147 @@
+use super::{
+    merkle_damgard::{self, ByteOrder, MerkleDamgard, CHUNK_LENGTH},
+    Hash,
+};
+use crate::{byte::*, CryptopalsError};
+use error_stack::Result;
+
+// Byte order of the state and the length field
+const BYTE_ORDER: ByteOrder = ByteOrder::BigEndian;
+
+// Initial state as defined in FIPS 180-4
+const INITIAL_STATE: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+// Round constants as defined in FIPS 180-4
+const ROUND_CONSTANTS: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5, 0xd807aa98,
+    0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174, 0xe49b69c1, 0xefbe4786,
+    0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da, 0x983e5152, 0xa831c66d, 0xb00327c8,
+    0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967, 0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13,
+    0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85, 0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819,
+    0xd6990624, 0xf40e3585, 0x106aa070, 0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a,
+    0x5b9cca4f, 0x682e6ff3, 0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7,
+    0xc67178f2,
+];
+
+/// The Secure Hash Algorithm 2 with a digest of 256 bits (SHA-256)
+///
+/// ## Examples
+/// ```
+/// use cryptopals::{byte::*, hash::{Hash, Sha256}};
+/// use byte_encoding_macro::hex;
+///
+/// let message = ByteSlice::from("abc");
+/// let expected = ByteSlice::from(hex!("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad").as_ref());
+///
+/// assert_eq!(Sha256::digest(&message), expected);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Sha256(MerkleDamgard<8>);
+
+impl Default for Sha256 {
+    fn default() -> Self {
+        Sha256(MerkleDamgard::new(INITIAL_STATE, 0))
+    }
+}
+
+// Compress a single chunk of 64 bytes into the state
+fn compress(state: &mut [u32; 8], chunk: &[u8]) {
+    // Message schedule
+    let mut words = [0u32; 64];
+    for (word, bytes) in words.iter_mut().zip(chunk.chunks(4)) {
+        *word = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    }
+    for index in 16..64 {
+        let s0 = words[index - 15].rotate_right(7) ^ words[index - 15].rotate_right(18) ^ (words[index - 15] >> 3);
+        let s1 = words[index - 2].rotate_right(17) ^ words[index - 2].rotate_right(19) ^ (words[index - 2] >> 10);
+        words[index] = words[index - 16]
+            .wrapping_add(s0)
+            .wrapping_add(words[index - 7])
+            .wrapping_add(s1);
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+
+    for (word, k) in words.into_iter().zip(ROUND_CONSTANTS) {
+        let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+        let choice = (e & f) ^ (!e & g);
+        let temp1 = h
+            .wrapping_add(s1)
+            .wrapping_add(choice)
+            .wrapping_add(k)
+            .wrapping_add(word);
+
+        let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+        let majority = (a & b) ^ (a & c) ^ (b & c);
+        let temp2 = s0.wrapping_add(majority);
+
+        h = g;
+        g = f;
+        f = e;
+        e = d.wrapping_add(temp1);
+        d = c;
+        c = b;
+        b = a;
+        a = temp1.wrapping_add(temp2);
+    }
+
+    // Add the compressed chunk to the current state
+    for (value, addition) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+        *value = value.wrapping_add(addition);
+    }
+}
+
+impl Hash for Sha256 {
+    const DIGEST_LENGTH: usize = 32;
+    const BLOCK_LENGTH: usize = CHUNK_LENGTH;
+
+    fn update(&mut self, message: &ByteSlice<'_>) {
+        self.0.update(message, compress);
+    }
+
+    fn finalize(self) -> ByteSlice<'static> {
+        self.0.finalize(compress, BYTE_ORDER)
+    }
+
+    fn padding(message_length: usize) -> ByteSlice<'static> {
+        merkle_damgard::padding(message_length, BYTE_ORDER)
+    }
+
+    fn from_digest(digest: &ByteSlice<'_>, length: usize) -> Result<Self, CryptopalsError> {
+        MerkleDamgard::from_digest(digest, length, BYTE_ORDER).map(Sha256)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::hmac;
+    use byte_encoding_macro::hex;
+
+    #[test]
+    fn empty_message() {
+        let digest = Sha256::digest(&ByteSlice::from(""));
+        let expected = hex!("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+
+        assert_eq!(digest, ByteSlice::from(expected.as_ref()));
+    }
+
+    #[test]
+    fn multiple_chunks() {
+        let message = ByteSlice::from("abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq");
+        let expected = hex!("248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1");
+
+        assert_eq!(Sha256::digest(&message), ByteSlice::from(expected.as_ref()));
+    }
+
+    #[test]
+    fn hmac_sha256() {
+        let key = ByteSlice::from("key");
+        let message = ByteSlice::from("The quick brown fox jumps over the lazy dog");
+        let expected = hex!("f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8");
+
+        assert_eq!(hmac::<Sha256>(&key, &message), ByteSlice::from(expected.as_ref()));
+    }
+}
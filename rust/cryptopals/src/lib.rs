@@ -9,5 +9,12 @@ pub use error::CryptopalsError;
 pub mod adversary;
 pub mod aes;
 pub mod byte;
+pub mod dh;
+pub mod dsa;
 mod error;
+pub mod hash;
+pub mod number_theory;
 pub mod oracle;
+pub mod rng;
+pub mod rsa;
+pub mod srp;
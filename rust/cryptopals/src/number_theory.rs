@@ -0,0 +1,48 @@
+//! Number theory that is shared by the public-key cryptosystems
+
+use crate::CryptopalsError;
+use error_stack::{ensure, Result};
+use num_bigint::{BigInt, BigUint, Sign};
+use num_traits::{One, Zero};
+
+/// Calculate the modular inverse `x` such that `a * x = 1 mod m`, using the extended Euclidean algorithm
+///
+/// The inverse only exists if `a` and `m` are coprime.
+///
+/// ## Examples
+/// ```
+/// use cryptopals::number_theory;
+/// use num_bigint::BigUint;
+///
+/// let inverse = number_theory::modular_inverse(&BigUint::from(17_u32), &BigUint::from(3120_u32))?;
+/// assert_eq!(inverse, BigUint::from(2753_u32));
+///
+/// let result = number_theory::modular_inverse(&BigUint::from(6_u32), &BigUint::from(9_u32));
+/// assert!(result.is_err());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn modular_inverse(a: &BigUint, m: &BigUint) -> Result<BigUint, CryptopalsError> {
+    let m = BigInt::from_biguint(Sign::Plus, m.clone());
+
+    // Invariant: old_s * a = old_r (mod m) and s * a = r (mod m)
+    let (mut old_r, mut r) = (BigInt::from_biguint(Sign::Plus, a.clone()) % &m, m.clone());
+    let (mut old_s, mut s) = (BigInt::one(), BigInt::zero());
+
+    while !r.is_zero() {
+        let quotient = &old_r / &r;
+
+        let next_r = &old_r - &quotient * &r;
+        old_r = std::mem::replace(&mut r, next_r);
+
+        let next_s = &old_s - &quotient * &s;
+        old_s = std::mem::replace(&mut s, next_s);
+    }
+
+    // The greatest common divisor should be 1
+    ensure!(old_r.is_one(), CryptopalsError::NoModularInverse);
+
+    // Make the result positive
+    let inverse = ((old_s % &m) + &m) % &m;
+
+    Ok(inverse.magnitude().clone())
+}
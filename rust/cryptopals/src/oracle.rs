@@ -2,18 +2,34 @@
 //!
 //! Each Oracle has unique characteristics which can be exploited by an
 //! adversary.
+pub use cbc_key_as_iv::CbcKeyAsIvOracle;
+pub use cbc_padding::CbcPaddingOracle;
+pub use ctr_edit::CtrEditOracle;
 pub use ecb_fixed_postfix::EcbFixedPostfixOracle;
 pub use error::OracleError;
 use error_stack::Result;
+pub use hmac_timing_leak::{HmacTimingLeakClient, HmacTimingLeakOracle};
 pub use random_block_mode::RandomBlockModeOracle;
+pub use recording::{RecordingOracle, Transcript};
+pub use replay::ReplayOracle;
+pub use rsa_unpadded::RsaUnpaddedOracle;
+pub use secret_prefix_mac::SecretPrefixMacOracle;
 pub use user_data::UserDataOracle;
-pub use user_profile::UserProfileOracle;
+pub use user_profile::{UserProfileField, UserProfileOracle};
 
-use crate::byte::*;
+use crate::{aes, byte::*};
 
+mod cbc_key_as_iv;
+mod cbc_padding;
+mod ctr_edit;
 mod ecb_fixed_postfix;
 mod error;
+mod hmac_timing_leak;
 mod random_block_mode;
+mod recording;
+mod replay;
+mod rsa_unpadded;
+mod secret_prefix_mac;
 mod user_data;
 mod user_profile;
 
@@ -49,3 +65,82 @@ pub trait Oracle: Default {
     /// Perform encrypt operation
     fn encrypt(&self, plaintext: ByteSlice<'_>) -> Result<ByteSlice<'static>, OracleError>;
 }
+
+/// Trait that should be implemented by oracles which decrypt a ciphertext and validate or check the result.
+///
+/// Oracles usually do not return the plaintext itself, but something derived from it. For example, a parsed profile
+/// or whether the plaintext contains a certain string.
+///
+/// ## Examples
+/// ```
+/// use cryptopals::{aes, byte::*, oracle::{DecryptOracle, Oracle, UserDataOracle}};
+///
+/// let oracle = UserDataOracle::with_block_mode(aes::BlockMode::Ctr);
+///
+/// // Regular user data does not make the user an admin
+/// let ciphertext = oracle.encrypt(ByteSlice::from("cryptopals"))?;
+/// assert_eq!(oracle.decrypt(ciphertext)?, false);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub trait DecryptOracle {
+    /// The result of a successful decryption
+    type Output;
+
+    /// Perform decrypt operation
+    fn decrypt(&self, ciphertext: ByteSlice<'_>) -> Result<Self::Output, OracleError>;
+}
+
+/// Trait that should be implemented by oracles which reveal whether a ciphertext has valid padding.
+///
+/// The oracle only answers with a boolean, but that is enough for an adversary to decrypt the ciphertext.
+///
+/// ## Examples
+/// ```
+/// use cryptopals::{aes, byte::*, oracle::{CbcPaddingOracle, PaddingOracle}};
+///
+/// let oracle = CbcPaddingOracle::default();
+///
+/// // The ciphertext of the oracle has valid padding
+/// assert!(oracle.check_padding(oracle.ciphertext().clone(), oracle.iv()));
+///
+/// // A ciphertext that is not a multiple of the block length can never have valid padding
+/// let ciphertext = ByteSlice::from("cryptopals");
+/// assert!(!oracle.check_padding(ciphertext, oracle.iv()));
+/// ```
+pub trait PaddingOracle {
+    /// Decrypt a ciphertext with the given IV and return whether the padding is valid
+    fn check_padding(&self, ciphertext: ByteSlice<'_>, iv: &aes::Block) -> bool;
+}
+
+/// Trait that should be implemented by oracles which verify a signature or MAC of a message.
+///
+/// The oracle only answers with a boolean, but the way it does so might leak information to an adversary.
+pub trait VerifyOracle {
+    /// Return whether the signature is valid for the message
+    fn verify(&self, message: &ByteSlice<'_>, signature: &ByteSlice<'_>) -> bool;
+}
+
+/// Trait that should be implemented by oracles which allow editing a ciphertext at an arbitrary offset.
+///
+/// ## Examples
+/// ```
+/// use cryptopals::{aes, byte::*, oracle::{CtrEditOracle, EditOracle}};
+///
+/// let key = aes::Key::from(*b"YELLOW SUBMARINE");
+/// let document = aes::ecb::encrypt(ByteSlice::from("Hello, World!"), &key);
+/// let oracle = CtrEditOracle::from_ecb_ciphertext(document, &key)?;
+///
+/// // Replace "World" with "Alice", which keeps the length the same
+/// let ciphertext = oracle.edit(oracle.ciphertext(), 7, &ByteSlice::from("Alice"))?;
+/// assert_eq!(ciphertext.length(), oracle.ciphertext().length());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub trait EditOracle {
+    /// Replace the bytes of the ciphertext at the given offset with the encryption of the new text
+    fn edit(
+        &self,
+        ciphertext: &ByteSlice<'_>,
+        offset: usize,
+        newtext: &ByteSlice<'_>,
+    ) -> Result<ByteSlice<'static>, OracleError>;
+}
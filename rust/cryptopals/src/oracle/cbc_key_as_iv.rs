@@ -0,0 +1,60 @@
+use super::{DecryptOracle, Oracle, OracleError};
+use crate::{aes, byte::*};
+use error_stack::{ensure, Result, ResultExt};
+use rand::Rng;
+
+/// An oracle which uses the encryption key as initialization vector.
+///
+/// During creation it will:
+///  - randomly generate an encryption key
+///
+/// During encryption it will:
+///  - encrypt the plaintext using AES CBC mode with the key as IV
+///
+/// During decryption it will:
+///  - decrypt the ciphertext using AES CBC mode with the key as IV
+///  - reject plaintexts with high-ASCII bytes, and return the offending plaintext in the error
+pub struct CbcKeyAsIvOracle {
+    key: aes::Key,
+    iv: aes::Block,
+}
+
+impl Default for CbcKeyAsIvOracle {
+    fn default() -> Self {
+        CbcKeyAsIvOracle::with_rng(&mut rand::thread_rng())
+    }
+}
+
+impl CbcKeyAsIvOracle {
+    /// Create oracle using the given random number generator, so the oracle can be reproduced from a seed
+    pub fn with_rng(rng: &mut impl Rng) -> Self {
+        // Generate a random key and use the same bytes as IV
+        let iv = aes::Block::with_random_values(rng);
+        let key = aes::Key::from(iv.clone());
+
+        CbcKeyAsIvOracle { key, iv }
+    }
+}
+
+impl Oracle for CbcKeyAsIvOracle {
+    fn encrypt(&self, plaintext: ByteSlice<'_>) -> Result<ByteSlice<'static>, OracleError> {
+        let ciphertext = aes::cbc::encrypt_with_iv(plaintext, &self.key, &self.iv);
+
+        Ok(ciphertext)
+    }
+}
+
+impl DecryptOracle for CbcKeyAsIvOracle {
+    type Output = ();
+
+    /// Decrypt a ciphertext and verify that the plaintext only contains ASCII characters
+    fn decrypt(&self, ciphertext: ByteSlice<'_>) -> Result<(), OracleError> {
+        let plaintext = aes::cbc::decrypt_with_iv(ciphertext, &self.key, &self.iv)
+            .change_context(OracleError::InvalidCiphertext)?;
+
+        // Leak the plaintext in the error message
+        ensure!(plaintext.iter().all(u8::is_ascii), OracleError::NonAsciiPlaintext(plaintext));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+use super::PaddingOracle;
+use crate::{aes, byte::*};
+use byte_encoding_macro::base64;
+use rand::Rng;
+
+// Secret strings of which one is randomly selected
+const SECRETS: [&[u8]; 10] = [
+    &base64!("MDAwMDAwTm93IHRoYXQgdGhlIHBhcnR5IGlzIGp1bXBpbmc="),
+    &base64!("MDAwMDAxV2l0aCB0aGUgYmFzcyBraWNrZWQgaW4gYW5kIHRoZSBWZWdhJ3MgYXJlIHB1bXBpbic="),
+    &base64!("MDAwMDAyUXVpY2sgdG8gdGhlIHBvaW50LCB0byB0aGUgcG9pbnQsIG5vIGZha2luZw=="),
+    &base64!("MDAwMDAzQ29va2luZyBNQydzIGxpa2UgYSBwb3VuZCBvZiBiYWNvbg=="),
+    &base64!("MDAwMDA0QnVybmluZyAnZW0sIGlmIHlvdSBhaW4ndCBxdWljayBhbmQgbmltYmxl"),
+    &base64!("MDAwMDA1SSBnbyBjcmF6eSB3aGVuIEkgaGVhciBhIGN5bWJhbA=="),
+    &base64!("MDAwMDA2QW5kIGEgaGlnaCBoYXQgd2l0aCBhIHNvdXBlZCB1cCB0ZW1wbw=="),
+    &base64!("MDAwMDA3SSdtIG9uIGEgcm9sbCwgaXQncyB0aW1lIHRvIGdvIHNvbG8="),
+    &base64!("MDAwMDA4b2xsaW4nIGluIG15IGZpdmUgcG9pbnQgb2g="),
+    &base64!("MDAwMDA5aXRoIG15IHJhZy10b3AgZG93biBzbyBteSBoYWlyIGNhbiBibG93"),
+];
+
+/// An oracle which encrypts a secret string and reveals whether a ciphertext has valid padding.
+///
+/// During creation it will:
+///  - randomly generate an encryption key
+///  - randomly generate an initialization vector
+///  - randomly select one of the secret strings
+///  - encrypt the secret string using AES CBC block mode
+///
+/// During padding checks it will:
+///  - decrypt the ciphertext using the given initialization vector
+///  - only reveal whether the padding of the plaintext was valid
+pub struct CbcPaddingOracle {
+    key: aes::Key,
+    iv: aes::Block,
+    plaintext: ByteSlice<'static>,
+    ciphertext: ByteSlice<'static>,
+}
+
+impl CbcPaddingOracle {
+    /// Return the encrypted secret string
+    pub fn ciphertext(&self) -> &ByteSlice<'static> {
+        &self.ciphertext
+    }
+
+    /// Return the initialization vector used to encrypt the secret string
+    pub fn iv(&self) -> &aes::Block {
+        &self.iv
+    }
+
+    /// Return the selected secret string, so it can be verified by the test case.
+    pub fn plaintext(&self) -> &ByteSlice<'static> {
+        &self.plaintext
+    }
+}
+
+impl Default for CbcPaddingOracle {
+    fn default() -> Self {
+        CbcPaddingOracle::with_rng(&mut rand::thread_rng())
+    }
+}
+
+impl CbcPaddingOracle {
+    /// Create oracle using the given random number generator, so the oracle can be reproduced from a seed
+    pub fn with_rng(rng: &mut impl Rng) -> Self {
+        // Generate a random key and IV
+        let key = aes::Key::with_random_values(rng);
+        let iv = aes::Block::with_random_values(rng);
+
+        // Select one of the secret strings
+        let index = rng.gen_range(0..SECRETS.len());
+        let plaintext = ByteSlice::from(SECRETS[index]);
+
+        // Encrypt using AES CBC block cipher mode
+        let ciphertext = aes::cbc::encrypt_with_iv(plaintext.clone(), &key, &iv);
+
+        CbcPaddingOracle {
+            key,
+            iv,
+            plaintext,
+            ciphertext,
+        }
+    }
+}
+
+impl PaddingOracle for CbcPaddingOracle {
+    fn check_padding(&self, ciphertext: ByteSlice<'_>, iv: &aes::Block) -> bool {
+        // Any error (including invalid length) is treated as invalid padding
+        aes::cbc::decrypt_with_iv(ciphertext, &self.key, iv).is_ok()
+    }
+}
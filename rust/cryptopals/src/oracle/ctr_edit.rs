@@ -0,0 +1,86 @@
+use super::{EditOracle, OracleError};
+use crate::{aes, byte::*};
+use error_stack::{ensure, Result, ResultExt};
+use rand::Rng;
+
+/// An oracle which holds an encrypted document and allows editing it at an arbitrary offset.
+///
+/// During creation it will:
+///  - decrypt the document using AES ECB mode with the given key
+///  - randomly generate an encryption key and nonce
+///  - encrypt the document using AES CTR mode
+///
+/// During editing it will:
+///  - encrypt the new text using the keystream at the given offset
+///  - replace the bytes of the ciphertext at the given offset
+pub struct CtrEditOracle {
+    key: aes::Key,
+    nonce: u64,
+    plaintext: ByteSlice<'static>,
+    ciphertext: ByteSlice<'static>,
+}
+
+impl CtrEditOracle {
+    /// Create oracle from a document which is encrypted using AES ECB mode, like the one of challenge 25
+    pub fn from_ecb_ciphertext(ciphertext: ByteSlice<'_>, key: &aes::Key) -> Result<CtrEditOracle, OracleError> {
+        CtrEditOracle::from_ecb_ciphertext_and_rng(ciphertext, key, &mut rand::thread_rng())
+    }
+
+    /// Create oracle from a document which is encrypted using AES ECB mode, using the given random number generator
+    pub fn from_ecb_ciphertext_and_rng(
+        ciphertext: ByteSlice<'_>,
+        key: &aes::Key,
+        rng: &mut impl Rng,
+    ) -> Result<CtrEditOracle, OracleError> {
+        // Decrypt the document
+        let plaintext = aes::ecb::decrypt(ciphertext, key).change_context(OracleError::InvalidCiphertext)?;
+
+        // Generate a random key and nonce
+        let key = aes::Key::with_random_values(rng);
+        let nonce = rng.gen();
+
+        // Encrypt using AES CTR block cipher mode
+        let ciphertext = aes::ctr::encrypt(plaintext.clone(), &key, nonce);
+
+        Ok(CtrEditOracle {
+            key,
+            nonce,
+            plaintext,
+            ciphertext,
+        })
+    }
+
+    /// Return the encrypted document
+    pub fn ciphertext(&self) -> &ByteSlice<'static> {
+        &self.ciphertext
+    }
+
+    /// Return the decrypted document, so it can be verified by the test case.
+    pub fn plaintext(&self) -> &ByteSlice<'static> {
+        &self.plaintext
+    }
+}
+
+impl EditOracle for CtrEditOracle {
+    fn edit(
+        &self,
+        ciphertext: &ByteSlice<'_>,
+        offset: usize,
+        newtext: &ByteSlice<'_>,
+    ) -> Result<ByteSlice<'static>, OracleError> {
+        ensure!(offset <= ciphertext.length(), OracleError::InvalidOffset(offset));
+
+        // Encrypt the new text using the keystream at the given offset
+        let encrypted_newtext = aes::ctr::encrypt_at_offset(newtext.clone(), &self.key, self.nonce, offset);
+
+        // Keep the bytes before and after the new text
+        let bytes = ciphertext
+            .iter()
+            .take(offset)
+            .chain(encrypted_newtext.iter())
+            .chain(ciphertext.iter().skip(offset + newtext.length()))
+            .copied();
+
+        Ok(ByteSlice::from_iter(bytes))
+    }
+}
@@ -1,25 +1,37 @@
 use super::{Oracle, OracleError};
-use crate::{aes, byte::*};
+use crate::{aes, byte::*, rng::Mt19937};
 use byte_encoding_macro::base64;
 use error_stack::Result;
 use rand::Rng;
+use std::{cell::RefCell, ops::Range};
 
 const POSTFIX: [u8; 138] = base64!("Um9sbGluJyBpbiBteSA1LjAKV2l0aCBteSByYWctdG9wIGRvd24gc28gbXkgaGFpciBjYW4gYmxvdwpUaGUgZ2lybGllcyBvbiBzdGFuZGJ5IHdhdmluZyBqdXN0IHRvIHNheSBoaQpEaWQgeW91IHN0b3A/IE5vLCBJIGp1c3QgZHJvdmUgYnkK");
 
+// Possible lengths of a random prefix
+const PREFIX_LENGTH_RANGE: Range<usize> = 1..32;
+
 /// An oracle which will encrypt a plaintext appended with a fixed string.
 ///
 /// During creation it will:
 ///  - randomly generate an encryption key
+///  - optionally, randomly generate a prefix of random length
 ///
 /// During encryption it will:
-///  - concatenate the plaintext with a fixed postfix
+///  - optionally, randomly generate a new prefix of random length
+///  - concatenate the prefix, plaintext and fixed postfix
 ///  - encrypt everything using AES ECB block cipher mode
 pub struct EcbFixedPostfixOracle {
     key: aes::Key,
-    prefix: ByteSlice<'static>,
+    prefix: Prefix,
     postfix: ByteSlice<'static>,
 }
 
+// Prefix which is either generated once, or generated again for every query
+enum Prefix {
+    Fixed(ByteSlice<'static>),
+    PerQuery(Box<RefCell<Mt19937>>),
+}
+
 impl EcbFixedPostfixOracle {
     /// Return the base64 encoded postfix, so it can be verified by the test case.
     pub fn postfix(&self) -> &ByteSlice<'static> {
@@ -28,23 +40,49 @@ impl EcbFixedPostfixOracle {
 
     /// Create oracle with a random prefix instead of an empty prefix
     pub fn with_random_prefix() -> Self {
+        EcbFixedPostfixOracle::with_random_prefix_and_rng(&mut rand::thread_rng())
+    }
+
+    /// Create oracle using the given random number generator, so the oracle can be reproduced from a seed
+    pub fn with_rng(rng: &mut impl Rng) -> Self {
+        // By default, the prefix length is zero
+        EcbFixedPostfixOracle::new(Some(0), rng)
+    }
+
+    /// Create oracle with a random prefix, using the given random number generator
+    pub fn with_random_prefix_and_rng(rng: &mut impl Rng) -> Self {
         // When the prefix length is not specified, a random one is generated
-        EcbFixedPostfixOracle::new(None)
+        EcbFixedPostfixOracle::new(None, rng)
     }
 
-    fn new(prefix_length_option: Option<usize>) -> Self {
-        let mut rng = rand::thread_rng();
+    /// Create oracle which generates a new prefix of random length for every query, instead of only once
+    pub fn with_random_prefix_per_query() -> Self {
+        EcbFixedPostfixOracle::with_random_prefix_per_query_and_rng(&mut rand::thread_rng())
+    }
 
+    /// Create oracle with a new random prefix for every query, using the given random number generator
+    pub fn with_random_prefix_per_query_and_rng(rng: &mut impl Rng) -> Self {
+        // Seed a generator of our own, since the prefixes are generated after creation
+        let prefix = Prefix::PerQuery(Box::new(RefCell::new(Mt19937::new(rng.gen()))));
+
+        EcbFixedPostfixOracle::with_prefix(prefix, rng)
+    }
+
+    fn new(prefix_length_option: Option<usize>, rng: &mut impl Rng) -> Self {
         // Use given length are randomly generate one
         let prefix_length = match prefix_length_option {
             Some(length) => length,
-            None => rng.gen_range(1..32),
+            None => rng.gen_range(PREFIX_LENGTH_RANGE),
         };
 
-        let prefix = ByteSlice::with_random_values_and_length(prefix_length, &mut rng);
+        let prefix = Prefix::Fixed(ByteSlice::with_random_values_and_length(prefix_length, rng));
+
+        EcbFixedPostfixOracle::with_prefix(prefix, rng)
+    }
 
+    fn with_prefix(prefix: Prefix, rng: &mut impl Rng) -> Self {
         // Generate a random key
-        let key = aes::Key::with_random_values(&mut rng);
+        let key = aes::Key::with_random_values(rng);
 
         // Initialize postfix from base64
         let postfix = ByteSlice::from(POSTFIX.as_ref());
@@ -55,15 +93,24 @@ impl EcbFixedPostfixOracle {
 
 impl Default for EcbFixedPostfixOracle {
     fn default() -> Self {
-        // By default, the prefix length is zero
-        EcbFixedPostfixOracle::new(Some(0))
+        EcbFixedPostfixOracle::with_rng(&mut rand::thread_rng())
     }
 }
 
 impl Oracle for EcbFixedPostfixOracle {
     fn encrypt(&self, plaintext: ByteSlice<'_>) -> Result<ByteSlice<'static>, OracleError> {
-        // Build a payload by adding the postfix to the plaintext
-        let payload = &self.prefix + plaintext + &self.postfix;
+        // Either use the fixed prefix or generate a new one
+        let prefix = match &self.prefix {
+            Prefix::Fixed(prefix) => prefix.clone(),
+            Prefix::PerQuery(rng) => {
+                let mut rng = rng.borrow_mut();
+                let prefix_length = rng.gen_range(PREFIX_LENGTH_RANGE);
+                ByteSlice::with_random_values_and_length(prefix_length, &mut *rng)
+            }
+        };
+
+        // Build a payload by adding the prefix and postfix to the plaintext
+        let payload = prefix + plaintext + &self.postfix;
 
         // Encrypt using AES ECB block cipher mode
         let ciphertext = aes::ecb::encrypt(payload, &self.key);
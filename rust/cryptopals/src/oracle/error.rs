@@ -1,3 +1,4 @@
+use crate::byte::*;
 use thiserror::Error;
 
 /// Error enum for errors in oracle
@@ -24,4 +25,25 @@ pub enum OracleError {
     /// This field does not exists in the key-value string.
     #[error("This field does not exists in the key-value string.")]
     UnexpectedField,
+    /// The offset lies beyond the end of the ciphertext.
+    #[error("The offset {0} lies beyond the end of the ciphertext.")]
+    InvalidOffset(usize),
+    /// The plaintext contains high-ASCII bytes.
+    #[error("The plaintext contains high-ASCII bytes: {0:?}")]
+    NonAsciiPlaintext(ByteSlice<'static>),
+    /// The oracle has already answered the maximum number of queries.
+    #[error("The query budget of {0} queries is exceeded.")]
+    QueryBudgetExceeded(usize),
+    /// The plaintext does not occur in the recorded transcript.
+    #[error("The plaintext does not occur in the recorded transcript.")]
+    UnrecordedQuery,
+    /// The transcript could not be parsed.
+    #[error("The transcript could not be parsed.")]
+    InvalidTranscript,
+    /// The message is not smaller than the modulus.
+    #[error("The message is not smaller than the modulus.")]
+    MessageTooLarge,
+    /// The same ciphertext was already decrypted during the retention period.
+    #[error("The same ciphertext was already decrypted during the retention period.")]
+    AlreadyDecrypted,
 }
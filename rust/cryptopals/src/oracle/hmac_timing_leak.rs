@@ -0,0 +1,236 @@
+use super::VerifyOracle;
+use crate::{
+    byte::*,
+    hash::{hmac, Sha1},
+};
+use byte_encoding::{decode_hexadecimal, encode_hexadecimal};
+use rand::Rng;
+use std::{
+    io::{self, BufRead, BufReader, Write},
+    net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+
+// Delay after each matching byte, as specified by challenge 31
+const DEFAULT_DELAY: Duration = Duration::from_millis(50);
+
+// Length of the random key
+const KEY_LENGTH: usize = 32;
+
+// Responses of the web server
+const STATUS_OK: &str = "HTTP/1.1 200 OK";
+const STATUS_INTERNAL_SERVER_ERROR: &str = "HTTP/1.1 500 Internal Server Error";
+
+/// An oracle which verifies HMAC-SHA1 signatures of files, but leaks timing information.
+///
+/// During creation it will:
+///  - randomly generate a key
+///
+/// During verification it will:
+///  - calculate the HMAC-SHA1 of the file
+///  - compare it byte by byte with the signature and exit early on the first difference
+///  - sleep for a fixed delay after each matching byte
+///
+/// The oracle can either be used in-process, or served over a loopback TCP socket using `spawn_server`.
+pub struct HmacTimingLeakOracle {
+    key: ByteSlice<'static>,
+    delay: Duration,
+}
+
+impl Default for HmacTimingLeakOracle {
+    fn default() -> Self {
+        HmacTimingLeakOracle::with_delay(DEFAULT_DELAY)
+    }
+}
+
+impl HmacTimingLeakOracle {
+    /// Create oracle using the given random number generator, so the oracle can be reproduced from a seed
+    pub fn with_rng(rng: &mut impl Rng) -> Self {
+        HmacTimingLeakOracle::with_delay_and_rng(DEFAULT_DELAY, rng)
+    }
+
+    /// Create oracle with a custom delay after each matching byte
+    pub fn with_delay(delay: Duration) -> Self {
+        HmacTimingLeakOracle::with_delay_and_rng(delay, &mut rand::thread_rng())
+    }
+
+    /// Create oracle with a custom delay after each matching byte and the given random number generator
+    pub fn with_delay_and_rng(delay: Duration, rng: &mut impl Rng) -> Self {
+        // Generate a random key
+        let key = ByteSlice::with_random_values_and_length(KEY_LENGTH, rng);
+
+        HmacTimingLeakOracle { key, delay }
+    }
+
+    /// Return the valid signature of a file, so it can be verified by the test case.
+    pub fn sign(&self, file: &ByteSlice<'_>) -> ByteSlice<'static> {
+        hmac::<Sha1>(&self.key, file)
+    }
+
+    /// Serve the oracle on a random port of the loopback interface.
+    ///
+    /// The server handles requests like `GET /test?file=foo&signature=46b4ec586117154dacd49d664e5d63fdc88efb51` on a
+    /// separate thread each, and responds with status 200 for a valid signature and status 500 otherwise. The query
+    /// values are percent-encoded. It stops once the returned client is dropped.
+    pub fn spawn_server(self) -> io::Result<HmacTimingLeakClient> {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))?;
+        let address = listener.local_addr()?;
+
+        // Flag to stop the server
+        let is_stopped = Arc::new(AtomicBool::new(false));
+        let is_server_stopped = Arc::clone(&is_stopped);
+
+        // Shared by the threads that handle requests
+        let oracle = Arc::new(self);
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                if is_server_stopped.load(Ordering::SeqCst) {
+                    break;
+                }
+
+                // Ignore connections that fail
+                if let Ok(stream) = stream {
+                    let oracle = Arc::clone(&oracle);
+                    thread::spawn(move || oracle.handle_request(stream));
+                }
+            }
+        });
+
+        Ok(HmacTimingLeakClient { address, is_stopped })
+    }
+
+    fn handle_request(&self, mut stream: TcpStream) -> io::Result<()> {
+        // Only the request line is needed
+        let mut request_line = String::new();
+        BufReader::new(&stream).read_line(&mut request_line)?;
+
+        let status = match parse_request_line(&request_line) {
+            Some((file, signature)) if self.verify(&file, &signature) => STATUS_OK,
+            _ => STATUS_INTERNAL_SERVER_ERROR,
+        };
+
+        write!(stream, "{}\r\n\r\n", status)
+    }
+
+    // Compare byte by byte and exit early, which leaks the number of matching bytes
+    fn insecure_compare(&self, lhs: &ByteSlice<'_>, rhs: &ByteSlice<'_>) -> bool {
+        for (lhs_byte, rhs_byte) in lhs.iter().zip(rhs.iter()) {
+            if lhs_byte != rhs_byte {
+                return false;
+            }
+
+            thread::sleep(self.delay);
+        }
+
+        lhs.length() == rhs.length()
+    }
+}
+
+impl VerifyOracle for HmacTimingLeakOracle {
+    fn verify(&self, file: &ByteSlice<'_>, signature: &ByteSlice<'_>) -> bool {
+        self.insecure_compare(&self.sign(file), signature)
+    }
+}
+
+// Parse a request line like `GET /test?file=foo&signature=0123 HTTP/1.1` into file and signature
+fn parse_request_line(request_line: &str) -> Option<(ByteSlice<'static>, ByteSlice<'static>)> {
+    let path = request_line.strip_prefix("GET ")?.split(' ').next()?;
+    let (_, query) = path.split_once('?')?;
+
+    // Uninitialized values
+    let mut file = None;
+    let mut signature = None;
+
+    for parameter in query.split('&') {
+        match parameter.split_once('=')? {
+            ("file", value) => file = Some(ByteSlice::from(percent_decode(value)?)),
+            ("signature", value) => {
+                let value = String::from_utf8(percent_decode(value)?).ok()?;
+                signature = Some(ByteSlice::from(decode_hexadecimal(value).ok()?));
+            }
+            _unknown_parameter => return None,
+        }
+    }
+
+    Some((file?, signature?))
+}
+
+// Encode all bytes except unreserved characters as `%XX`
+fn percent_encode(bytes: impl Iterator<Item = u8>) -> String {
+    bytes
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => char::from(byte).to_string(),
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+// Decode `%XX` sequences, which is the inverse of `percent_encode`
+fn percent_decode(value: &str) -> Option<Vec<u8>> {
+    let mut bytes = value.bytes();
+    let mut decoded = Vec::with_capacity(value.len());
+
+    while let Some(byte) = bytes.next() {
+        match byte {
+            b'%' => {
+                let hexadecimal = [bytes.next()?, bytes.next()?];
+                let value = decode_hexadecimal(std::str::from_utf8(&hexadecimal).ok()?).ok()?;
+                decoded.extend(value);
+            }
+            _ => decoded.push(byte),
+        }
+    }
+
+    Some(decoded)
+}
+
+/// A client of the web server which is spawned by `HmacTimingLeakOracle::spawn_server`
+pub struct HmacTimingLeakClient {
+    address: SocketAddr,
+    is_stopped: Arc<AtomicBool>,
+}
+
+impl HmacTimingLeakClient {
+    /// Address of the web server
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+}
+
+impl VerifyOracle for HmacTimingLeakClient {
+    /// Send a request to the web server
+    fn verify(&self, file: &ByteSlice<'_>, signature: &ByteSlice<'_>) -> bool {
+        let send_request = || -> io::Result<bool> {
+            let mut stream = TcpStream::connect(self.address)?;
+
+            // Send request with percent-encoded query values
+            let file = percent_encode(file.iter().copied());
+            let signature = encode_hexadecimal(signature.iter().copied().collect::<Vec<_>>());
+            let signature = percent_encode(signature.bytes());
+            write!(stream, "GET /test?file={}&signature={} HTTP/1.1\r\n\r\n", file, signature)?;
+
+            // Read status line of response
+            let mut status_line = String::new();
+            BufReader::new(&stream).read_line(&mut status_line)?;
+
+            Ok(status_line.trim_end() == STATUS_OK)
+        };
+
+        // A failed request does not prove the signature is valid
+        send_request().unwrap_or(false)
+    }
+}
+
+impl Drop for HmacTimingLeakClient {
+    fn drop(&mut self) {
+        // Signal the server to stop and wake it up with one last connection
+        self.is_stopped.store(true, Ordering::SeqCst);
+        let _ = TcpStream::connect(self.address);
+    }
+}
@@ -8,7 +8,8 @@ use crate::{aes, byte::*};
 ///
 /// During creation it will:
 ///  - randomly generate an encryption key
-///  - randomly selected block cipher mode; either ECB or CBC
+///  - randomly selected block cipher mode; either ECB, CBC or CTR
+///  - randomly generate a nonce (only used in CTR mode)
 ///  - randomly generate a prefix between 5 and 10 bytes
 ///  - randomly generate a postfix between 5 and 10 bytes
 ///
@@ -18,46 +19,69 @@ use crate::{aes, byte::*};
 pub struct RandomBlockModeOracle {
     key: aes::Key,
     mode: aes::BlockMode,
+    nonce: u64,
     prefix: ByteSlice<'static>,
     postfix: ByteSlice<'static>,
 }
 
 impl RandomBlockModeOracle {
-    /// Return the randomly selected block mode, so it can be verified by the
-    /// test case.
-    pub fn block_mode(&self) -> &aes::BlockMode {
-        &self.mode
-    }
-}
-
-impl Default for RandomBlockModeOracle {
-    fn default() -> Self {
-        let mut rng = rand::thread_rng();
-
+    /// Create oracle using the given random number generator, so the oracle can be reproduced from a seed
+    ///
+    /// ## Examples
+    /// ```
+    /// use cryptopals::{byte::*, oracle::{Oracle, RandomBlockModeOracle}, rng::Mt19937};
+    ///
+    /// let oracle_1 = RandomBlockModeOracle::with_rng(&mut Mt19937::new(5489));
+    /// let oracle_2 = RandomBlockModeOracle::with_rng(&mut Mt19937::new(5489));
+    ///
+    /// // The same seed results in the same block mode, key, prefix and postfix
+    /// let plaintext = ByteSlice::from("cryptopals");
+    /// assert_eq!(oracle_1.block_mode(), oracle_2.block_mode());
+    /// assert_eq!(oracle_1.encrypt(plaintext.clone())?, oracle_2.encrypt(plaintext)?);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_rng(rng: &mut impl Rng) -> Self {
         // Generate a random key
-        let key = aes::Key::with_random_values(&mut rng);
+        let key = aes::Key::with_random_values(rng);
 
-        // Generate a random bool in order to pick between the two block modes
-        let mode = match rng.gen() {
-            true => aes::BlockMode::Ecb,
-            false => aes::BlockMode::Cbc,
+        // Generate a random number in order to pick between the three block modes
+        let mode = match rng.gen_range(0..3) {
+            0 => aes::BlockMode::Ecb,
+            1 => aes::BlockMode::Cbc,
+            _ => aes::BlockMode::Ctr,
         };
 
+        // Generate a random nonce
+        let nonce = rng.gen();
+
         // Generate a random length
         let prefix_length = rng.gen_range(5..=10);
         let postfix_length = rng.gen_range(5..=10);
 
         // Generate random bytes
-        let prefix = ByteSlice::with_random_values_and_length(prefix_length, &mut rng);
-        let postfix = ByteSlice::with_random_values_and_length(postfix_length, &mut rng);
+        let prefix = ByteSlice::with_random_values_and_length(prefix_length, rng);
+        let postfix = ByteSlice::with_random_values_and_length(postfix_length, rng);
 
         RandomBlockModeOracle {
             key,
             mode,
+            nonce,
             prefix,
             postfix,
         }
     }
+
+    /// Return the randomly selected block mode, so it can be verified by the
+    /// test case.
+    pub fn block_mode(&self) -> &aes::BlockMode {
+        &self.mode
+    }
+}
+
+impl Default for RandomBlockModeOracle {
+    fn default() -> Self {
+        RandomBlockModeOracle::with_rng(&mut rand::thread_rng())
+    }
 }
 
 impl Oracle for RandomBlockModeOracle {
@@ -69,6 +93,7 @@ impl Oracle for RandomBlockModeOracle {
         let ciphertext = match self.mode {
             aes::BlockMode::Ecb => aes::ecb::encrypt(payload, &self.key),
             aes::BlockMode::Cbc => aes::cbc::encrypt(payload, &self.key),
+            aes::BlockMode::Ctr => aes::ctr::encrypt(payload, &self.key, self.nonce),
         };
 
         Ok(ciphertext)
@@ -0,0 +1,200 @@
+use super::{Oracle, OracleError};
+use crate::byte::*;
+use byte_encoding::{decode_hexadecimal, encode_hexadecimal};
+use error_stack::{bail, ensure, Report, Result};
+use std::{
+    cell::{Cell, RefCell},
+    fmt,
+};
+
+/// A wrapper around any oracle which keeps track of the queries of an adversary.
+///
+/// During creation it will:
+///  - wrap the given oracle
+///  - optionally set a budget for the number of queries
+///
+/// During encryption it will:
+///  - refuse the query if the budget is exhausted
+///  - count the query, even if the wrapped oracle fails
+///  - pass the plaintext on to the wrapped oracle
+///  - record the plaintext and ciphertext of successful queries in a transcript
+///
+/// ## Examples
+/// ```
+/// use cryptopals::{byte::*, oracle::{EcbFixedPostfixOracle, Oracle, RecordingOracle}};
+///
+/// let oracle = RecordingOracle::with_budget(EcbFixedPostfixOracle::default(), 1);
+///
+/// assert!(oracle.encrypt(ByteSlice::from("cryptopals")).is_ok());
+/// assert!(oracle.encrypt(ByteSlice::from("cryptopals")).is_err());
+///
+/// assert_eq!(oracle.query_count(), 1);
+/// assert_eq!(oracle.bytes_sent(), 10);
+/// ```
+///
+/// Queries that are rejected by the wrapped oracle count towards the budget as well:
+/// ```
+/// use cryptopals::{byte::*, oracle::{Oracle, RecordingOracle, UserDataOracle}};
+///
+/// let oracle = RecordingOracle::with_budget(UserDataOracle::default(), 1);
+///
+/// assert!(oracle.encrypt(ByteSlice::from(";admin=true;")).is_err());
+/// assert!(oracle.encrypt(ByteSlice::from("cryptopals")).is_err());
+///
+/// assert_eq!(oracle.query_count(), 1);
+/// assert_eq!(oracle.transcript().length(), 0);
+/// ```
+pub struct RecordingOracle<O: Oracle> {
+    oracle: O,
+    budget: Option<usize>,
+    query_count: Cell<usize>,
+    transcript: RefCell<Transcript>,
+}
+
+impl<O: Oracle> Default for RecordingOracle<O> {
+    fn default() -> Self {
+        RecordingOracle::new(O::default())
+    }
+}
+
+impl<O: Oracle> RecordingOracle<O> {
+    /// Create wrapper without a budget for the number of queries
+    pub fn new(oracle: O) -> Self {
+        RecordingOracle {
+            oracle,
+            budget: None,
+            query_count: Cell::new(0),
+            transcript: RefCell::new(Transcript::default()),
+        }
+    }
+
+    /// Create wrapper which refuses any query after the budget is exhausted
+    pub fn with_budget(oracle: O, budget: usize) -> Self {
+        RecordingOracle {
+            budget: Some(budget),
+            ..RecordingOracle::new(oracle)
+        }
+    }
+
+    /// Return the wrapped oracle, so its secrets can be verified by the test case.
+    pub fn oracle(&self) -> &O {
+        &self.oracle
+    }
+
+    /// Return the number of queries that were passed on to the wrapped oracle, including failed ones
+    pub fn query_count(&self) -> usize {
+        self.query_count.get()
+    }
+
+    /// Return the total number of plaintext bytes of the successful queries
+    pub fn bytes_sent(&self) -> usize {
+        self.transcript
+            .borrow()
+            .queries()
+            .map(|(plaintext, _)| plaintext.length())
+            .sum()
+    }
+
+    /// Return a copy of the transcript of all successful queries
+    pub fn transcript(&self) -> Transcript {
+        self.transcript.borrow().clone()
+    }
+}
+
+impl<O: Oracle> Oracle for RecordingOracle<O> {
+    fn encrypt(&self, plaintext: ByteSlice<'_>) -> Result<ByteSlice<'static>, OracleError> {
+        // Refuse the query once the budget is exhausted
+        if let Some(budget) = self.budget {
+            ensure!(self.query_count() < budget, OracleError::QueryBudgetExceeded(budget));
+        }
+
+        // Failed queries count towards the budget as well
+        self.query_count.set(self.query_count() + 1);
+
+        // Keep an owned copy of the plaintext for the transcript
+        let plaintext = ByteSlice::from_iter(plaintext.into_iter());
+        let ciphertext = self.oracle.encrypt(plaintext.clone())?;
+
+        self.transcript
+            .borrow_mut()
+            .push(plaintext, ciphertext.clone());
+
+        Ok(ciphertext)
+    }
+}
+
+/// The plaintexts and ciphertexts of all queries to an oracle, in order.
+///
+/// The transcript can be serialized as text with one query per line, where the plaintext and ciphertext are hexadecimal
+/// encoded and separated by a vertical bar.
+///
+/// ## Examples
+/// ```
+/// use cryptopals::{byte::*, oracle::Transcript};
+///
+/// let mut transcript = Transcript::default();
+/// transcript.push(ByteSlice::from("hi"), ByteSlice::from("OK"));
+///
+/// let text = transcript.to_string();
+/// assert_eq!(text, "68 69 | 4F 4B\n");
+///
+/// assert_eq!(Transcript::try_from(text.as_str())?, transcript);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Transcript(Vec<(ByteSlice<'static>, ByteSlice<'static>)>);
+
+impl Transcript {
+    /// Add a query at the end of the transcript
+    pub fn push(&mut self, plaintext: ByteSlice<'static>, ciphertext: ByteSlice<'static>) {
+        self.0.push((plaintext, ciphertext));
+    }
+
+    /// Return the number of queries
+    pub fn length(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Return an iterator over the plaintext and ciphertext of each query
+    pub fn queries(&self) -> impl Iterator<Item = &(ByteSlice<'static>, ByteSlice<'static>)> {
+        self.0.iter()
+    }
+}
+
+impl fmt::Display for Transcript {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let encode = |value: &ByteSlice<'_>| encode_hexadecimal(value.iter().copied().collect::<Vec<_>>());
+
+        for (plaintext, ciphertext) in self.queries() {
+            writeln!(f, "{} | {}", encode(plaintext), encode(ciphertext))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<&str> for Transcript {
+    type Error = Report<OracleError>;
+
+    /// Parse a transcript that was serialized using `to_string`
+    fn try_from(value: &str) -> Result<Self, OracleError> {
+        let mut transcript = Transcript::default();
+
+        for line in value.lines() {
+            let Some((plaintext, ciphertext)) = line.split_once('|') else {
+                bail!(OracleError::InvalidTranscript);
+            };
+
+            let plaintext = decode_hexadecimal(plaintext)
+                .ok()
+                .ok_or(OracleError::InvalidTranscript)?;
+            let ciphertext = decode_hexadecimal(ciphertext)
+                .ok()
+                .ok_or(OracleError::InvalidTranscript)?;
+
+            transcript.push(ByteSlice::from(plaintext), ByteSlice::from(ciphertext));
+        }
+
+        Ok(transcript)
+    }
+}
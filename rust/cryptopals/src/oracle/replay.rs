@@ -0,0 +1,63 @@
+use super::{Oracle, OracleError, Transcript};
+use crate::byte::*;
+use error_stack::Result;
+use std::collections::HashMap;
+
+/// An oracle which replays a recorded transcript, so an attack can be repeated offline.
+///
+/// During creation it will:
+///  - store the ciphertext of each plaintext in the transcript of a `RecordingOracle`
+///
+/// During encryption it will:
+///  - look up the plaintext and return the recorded ciphertext
+///
+/// The order of the queries does not need to match the recording. If a plaintext was recorded multiple times, the first
+/// ciphertext is returned.
+///
+/// ## Examples
+/// ```
+/// use cryptopals::{byte::*, oracle::{EcbFixedPostfixOracle, Oracle, RecordingOracle, ReplayOracle}};
+///
+/// let oracle = RecordingOracle::new(EcbFixedPostfixOracle::default());
+/// let ciphertext = oracle.encrypt(ByteSlice::from("cryptopals"))?;
+///
+/// let replay = ReplayOracle::new(oracle.transcript());
+/// assert_eq!(replay.encrypt(ByteSlice::from("cryptopals"))?, ciphertext);
+///
+/// // The transcript does not contain this plaintext
+/// assert!(replay.encrypt(ByteSlice::from("cryptopal")).is_err());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Default)]
+pub struct ReplayOracle {
+    ciphertexts: HashMap<ByteSlice<'static>, ByteSlice<'static>>,
+}
+
+impl ReplayOracle {
+    /// Create oracle which answers queries from the given transcript
+    pub fn new(transcript: Transcript) -> Self {
+        let mut ciphertexts = HashMap::new();
+
+        for (plaintext, ciphertext) in transcript.queries() {
+            ciphertexts
+                .entry(plaintext.clone())
+                .or_insert_with(|| ciphertext.clone());
+        }
+
+        ReplayOracle { ciphertexts }
+    }
+}
+
+impl Oracle for ReplayOracle {
+    fn encrypt(&self, plaintext: ByteSlice<'_>) -> Result<ByteSlice<'static>, OracleError> {
+        let plaintext = ByteSlice::from_iter(plaintext.into_iter());
+
+        // Only plaintexts from the recording can be answered
+        let ciphertext = self
+            .ciphertexts
+            .get(&plaintext)
+            .ok_or(OracleError::UnrecordedQuery)?;
+
+        Ok(ciphertext.clone())
+    }
+}
@@ -0,0 +1,114 @@
+use super::{DecryptOracle, Oracle, OracleError};
+use crate::{
+    byte::*,
+    hash::{Hash, Sha256},
+    rsa,
+};
+use error_stack::{ensure, Result};
+use num_bigint::BigUint;
+use rand::Rng;
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+// Number of bits of the modulus
+const MODULUS_BITS: u64 = 1024;
+
+// Period during which the same ciphertext will not be decrypted again
+const DEFAULT_RETENTION_PERIOD: Duration = Duration::from_secs(60 * 60);
+
+/// An oracle which decrypts unpadded RSA ciphertexts, but refuses to decrypt the same ciphertext twice.
+///
+/// During creation it will:
+///  - randomly generate an RSA key pair
+///
+/// During encryption it will:
+///  - encrypt the plaintext using raw RSA with the public key
+///
+/// During decryption it will:
+///  - forget the hashes that were stored longer than the retention period ago
+///  - refuse the ciphertext if its hash was seen within the retention period
+///  - store the hash of the ciphertext together with the current time
+///  - decrypt the ciphertext using raw RSA with the private key
+///
+/// ## Examples
+/// ```
+/// use cryptopals::{byte::*, oracle::{DecryptOracle, Oracle, RsaUnpaddedOracle}};
+///
+/// let oracle = RsaUnpaddedOracle::default();
+/// let ciphertext = oracle.encrypt(ByteSlice::from("cryptopals"))?;
+///
+/// assert_eq!(oracle.decrypt(ciphertext.clone())?, ByteSlice::from("cryptopals"));
+/// assert!(oracle.decrypt(ciphertext).is_err());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct RsaUnpaddedOracle {
+    private_key: rsa::PrivateKey,
+    retention_period: Duration,
+    seen: RefCell<HashMap<ByteSlice<'static>, Instant>>,
+}
+
+impl Default for RsaUnpaddedOracle {
+    fn default() -> Self {
+        RsaUnpaddedOracle::with_rng(&mut rand::thread_rng())
+    }
+}
+
+impl RsaUnpaddedOracle {
+    /// Create oracle using the given random number generator, so the oracle can be reproduced from a seed
+    pub fn with_rng(rng: &mut impl Rng) -> Self {
+        RsaUnpaddedOracle::with_retention_period_and_rng(DEFAULT_RETENTION_PERIOD, rng)
+    }
+
+    /// Create oracle which decrypts the same ciphertext again once the retention period has passed
+    pub fn with_retention_period_and_rng(retention_period: Duration, rng: &mut impl Rng) -> Self {
+        let private_key = rsa::PrivateKey::generate(MODULUS_BITS, rng);
+
+        RsaUnpaddedOracle {
+            private_key,
+            retention_period,
+            seen: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Return the public key, which is known to the adversary
+    pub fn public_key(&self) -> &rsa::PublicKey {
+        self.private_key.public_key()
+    }
+}
+
+impl Oracle for RsaUnpaddedOracle {
+    fn encrypt(&self, plaintext: ByteSlice<'_>) -> Result<ByteSlice<'static>, OracleError> {
+        let message = BigUint::from(&plaintext);
+        ensure!(message < *self.public_key().n(), OracleError::MessageTooLarge);
+
+        let ciphertext = self.public_key().encrypt(&message);
+
+        Ok(ByteSlice::from(&ciphertext))
+    }
+}
+
+impl DecryptOracle for RsaUnpaddedOracle {
+    type Output = ByteSlice<'static>;
+
+    /// Decrypt a ciphertext, unless the same ciphertext was decrypted recently
+    fn decrypt(&self, ciphertext: ByteSlice<'_>) -> Result<ByteSlice<'static>, OracleError> {
+        // Reduce the ciphertext, so leading zeroes or adding the modulus does not result in a different hash
+        let ciphertext = BigUint::from(&ciphertext) % self.public_key().n();
+        let hash = Sha256::digest(&ByteSlice::from(&ciphertext));
+
+        let now = Instant::now();
+        let mut seen = self.seen.borrow_mut();
+
+        // Prune expired hashes, so the oracle does not keep growing
+        seen.retain(|_, timestamp| now.duration_since(*timestamp) < self.retention_period);
+        ensure!(!seen.contains_key(&hash), OracleError::AlreadyDecrypted);
+        seen.insert(hash, now);
+
+        let plaintext = self.private_key.decrypt(&ciphertext);
+
+        Ok(ByteSlice::from(&plaintext))
+    }
+}
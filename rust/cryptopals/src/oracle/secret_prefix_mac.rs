@@ -0,0 +1,54 @@
+use super::VerifyOracle;
+use crate::{
+    byte::*,
+    hash::{Hash, Sha1},
+};
+use rand::Rng;
+use std::marker::PhantomData;
+
+/// An oracle which authenticates messages with a secret-prefix MAC, i.e. `H(key || message)`.
+///
+/// During creation it will:
+///  - randomly generate a key of random length
+///
+/// During signing it will:
+///  - hash the concatenation of the key and message
+///
+/// During verification it will:
+///  - compare the MAC with the signature of the message
+pub struct SecretPrefixMacOracle<H: Hash = Sha1> {
+    key: ByteSlice<'static>,
+    hash: PhantomData<H>,
+}
+
+impl<H: Hash> Default for SecretPrefixMacOracle<H> {
+    fn default() -> Self {
+        SecretPrefixMacOracle::with_rng(&mut rand::thread_rng())
+    }
+}
+
+impl<H: Hash> SecretPrefixMacOracle<H> {
+    /// Create oracle using the given random number generator, so the oracle can be reproduced from a seed
+    pub fn with_rng(rng: &mut impl Rng) -> Self {
+        // Generate a random key of random length
+        let key_length = rng.gen_range(8..=32);
+        let key = ByteSlice::with_random_values_and_length(key_length, rng);
+
+        SecretPrefixMacOracle {
+            key,
+            hash: PhantomData,
+        }
+    }
+
+    /// Generate a MAC for the message
+    pub fn sign(&self, message: &ByteSlice<'_>) -> ByteSlice<'static> {
+        H::digest(&(&self.key + message))
+    }
+}
+
+impl<H: Hash> VerifyOracle for SecretPrefixMacOracle<H> {
+    /// Verify whether the MAC is valid for the message
+    fn verify(&self, message: &ByteSlice<'_>, mac: &ByteSlice<'_>) -> bool {
+        &self.sign(message) == mac
+    }
+}
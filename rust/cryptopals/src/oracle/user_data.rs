@@ -1,6 +1,7 @@
-use super::{Oracle, OracleError};
+use super::{DecryptOracle, Oracle, OracleError};
 use crate::{aes, byte::*};
-use error_stack::{ensure, Result};
+use error_stack::{ensure, Result, ResultExt};
+use rand::Rng;
 
 // Special characters
 const CHARACTER_SEMICOLON: char = ';';
@@ -8,32 +9,66 @@ const CHARACTER_EQUALS_SIGN: char = '=';
 const BYTE_SEMICOLON: u8 = CHARACTER_SEMICOLON as u8;
 const BYTE_EQUALS_SIGN: u8 = CHARACTER_EQUALS_SIGN as u8;
 
+// Marker of an admin user in the decrypted user data
+const ADMIN_MARKER: &[u8] = b";admin=true;";
+
 /// An oracle which takes a plaintext and encrypts it.
 ///
 /// During creation it will:
 ///  - randomly generate an encryption key
+///  - randomly generate a nonce (only used in CTR mode)
 ///
 /// During encryption it will:
 ///  - concatenate the plaintext with a fixed prefix
 ///  - concatenate the plaintext with a fixed postfix
+///  - encrypt everything using the block mode of the oracle (CBC by default)
 pub struct UserDataOracle {
     key: aes::Key,
+    mode: aes::BlockMode,
+    nonce: u64,
     prefix: ByteSlice<'static>,
     postfix: ByteSlice<'static>,
 }
 
 impl Default for UserDataOracle {
     fn default() -> Self {
-        let mut rng = rand::thread_rng();
+        UserDataOracle::with_block_mode(aes::BlockMode::Cbc)
+    }
+}
+
+impl UserDataOracle {
+    /// Create oracle which uses the given block mode
+    pub fn with_block_mode(mode: aes::BlockMode) -> Self {
+        UserDataOracle::with_block_mode_and_rng(mode, &mut rand::thread_rng())
+    }
+
+    /// Create oracle using the given random number generator, so the oracle can be reproduced from a seed
+    pub fn with_rng(rng: &mut impl Rng) -> Self {
+        UserDataOracle::with_block_mode_and_rng(aes::BlockMode::Cbc, rng)
+    }
 
-        // Generate a random key
-        let key = aes::Key::with_random_values(&mut rng);
+    /// Create oracle which uses the given block mode and random number generator
+    pub fn with_block_mode_and_rng(mode: aes::BlockMode, rng: &mut impl Rng) -> Self {
+        // Generate a random key and nonce
+        let key = aes::Key::with_random_values(rng);
+        let nonce = rng.gen();
 
         // Initialize pre- and postfix
         let prefix = ByteSlice::from("comment1=cooking%20MCs;userdata=");
         let postfix = ByteSlice::from(";comment2=%20like%20a%20pound%20of%20bacon");
 
-        UserDataOracle { key, prefix, postfix }
+        UserDataOracle {
+            key,
+            mode,
+            nonce,
+            prefix,
+            postfix,
+        }
+    }
+
+    /// Return the block mode of the oracle, so it can be verified by the test case.
+    pub fn block_mode(&self) -> &aes::BlockMode {
+        &self.mode
     }
 }
 
@@ -47,8 +82,34 @@ impl Oracle for UserDataOracle {
         let payload = &self.prefix + plaintext + &self.postfix;
 
         // Encrypt
-        let ciphertext = aes::cbc::encrypt(payload, &self.key);
+        let ciphertext = match self.mode {
+            aes::BlockMode::Ecb => aes::ecb::encrypt(payload, &self.key),
+            aes::BlockMode::Cbc => aes::cbc::encrypt(payload, &self.key),
+            aes::BlockMode::Ctr => aes::ctr::encrypt(payload, &self.key, self.nonce),
+        };
 
         Ok(ciphertext)
     }
 }
+
+impl DecryptOracle for UserDataOracle {
+    type Output = bool;
+
+    /// Decrypt the ciphertext and return whether the user data contains `;admin=true;`
+    fn decrypt(&self, ciphertext: ByteSlice<'_>) -> Result<bool, OracleError> {
+        let plaintext = match self.mode {
+            aes::BlockMode::Ecb => aes::ecb::decrypt(ciphertext, &self.key),
+            aes::BlockMode::Cbc => aes::cbc::decrypt(ciphertext, &self.key),
+            aes::BlockMode::Ctr => Ok(aes::ctr::decrypt(ciphertext, &self.key, self.nonce)),
+        }
+        .change_context(OracleError::InvalidCiphertext)?;
+
+        // Search for the admin marker
+        let bytes = plaintext.iter().copied().collect::<Vec<_>>();
+        let is_admin = bytes
+            .windows(ADMIN_MARKER.len())
+            .any(|window| window == ADMIN_MARKER);
+
+        Ok(is_admin)
+    }
+}
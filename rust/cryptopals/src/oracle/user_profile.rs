@@ -1,6 +1,7 @@
-use super::{Oracle, OracleError};
+use super::{DecryptOracle, Oracle, OracleError};
 use crate::{aes, byte::*};
 use error_stack::{bail, ensure, IntoReport, Result, ResultExt};
+use rand::Rng;
 use std::cell::RefCell;
 
 // Special characters for URL-encoding
@@ -9,17 +10,39 @@ const CHARACTER_EQUALS_SIGN: char = '=';
 const BYTE_AMPERSAND: u8 = CHARACTER_AMPERSAND as u8;
 const BYTE_EQUALS_SIGN: u8 = CHARACTER_EQUALS_SIGN as u8;
 
+// Order of the fields in a regular profile
+const DEFAULT_FIELD_ORDER: [UserProfileField; 3] = [
+    UserProfileField::Email,
+    UserProfileField::Uid,
+    UserProfileField::Role,
+];
+
 /// An oracle which takes a plaintext email address and creates an encrypted
 /// token for it.
 ///
 /// During creation it will:
 ///  - randomly generate an encryption key
+///
+/// During encryption it will:
+///  - build a URL-encoded profile with the email address, a new user ID and the user role (in that order by default)
+///  - encrypt everything using AES ECB block cipher mode
 pub struct UserProfileOracle {
     key: aes::Key,
-    prefix: ByteSlice<'static>,
+    field_order: [UserProfileField; 3],
     latest_id: RefCell<usize>,
 }
 
+/// Field of the URL-encoded profile
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UserProfileField {
+    /// The email address, which is the plaintext of the oracle
+    Email,
+    /// The automatically incremented user ID
+    Uid,
+    /// The role, which is always user
+    Role,
+}
+
 /// Role of a user profile
 #[derive(Debug, PartialEq)]
 pub enum UserRole {
@@ -39,13 +62,25 @@ pub struct UserProfile {
 
 impl Default for UserProfileOracle {
     fn default() -> Self {
-        let mut rng = rand::thread_rng();
+        UserProfileOracle::with_rng(&mut rand::thread_rng())
+    }
+}
 
-        // Generate a random key
-        let key = aes::Key::with_random_values(&mut rng);
+impl UserProfileOracle {
+    /// Create oracle using the given random number generator, so the oracle can be reproduced from a seed
+    pub fn with_rng(rng: &mut impl Rng) -> Self {
+        UserProfileOracle::with_field_order_and_rng(DEFAULT_FIELD_ORDER, rng)
+    }
 
-        // Prefix will always be the same, so we can already make a Bytes struct
-        let prefix = ByteSlice::from("email=");
+    /// Create oracle which puts the fields of the profile in the given order
+    pub fn with_field_order(field_order: [UserProfileField; 3]) -> Self {
+        UserProfileOracle::with_field_order_and_rng(field_order, &mut rand::thread_rng())
+    }
+
+    /// Create oracle which puts the fields of the profile in the given order, using the given random number generator
+    pub fn with_field_order_and_rng(field_order: [UserProfileField; 3], rng: &mut impl Rng) -> Self {
+        // Generate a random key
+        let key = aes::Key::with_random_values(rng);
 
         // Auto increment the UUID of the user, but start with a number that won't
         // likely impact the padding of the ciphertext
@@ -53,7 +88,7 @@ impl Default for UserProfileOracle {
 
         UserProfileOracle {
             key,
-            prefix,
+            field_order,
             latest_id,
         }
     }
@@ -68,12 +103,23 @@ impl Oracle for UserProfileOracle {
         // Mutably borrow from an immutable RefCell
         let mut id = self.latest_id.borrow_mut();
 
-        // Generate postfix and increment counter
-        let postfix = ByteSlice::from(format!("&uid={}&role=user", id));
-        *id += 1;
-
         // Build a profile by embedding the email into a URL-encoded string
-        let profile = &self.prefix + email + postfix;
+        let mut profile = ByteSlice::from(Vec::new());
+        for (index, field) in self.field_order.iter().enumerate() {
+            if index > 0 {
+                profile.push(BYTE_AMPERSAND);
+            }
+
+            profile = profile
+                + match field {
+                    UserProfileField::Email => ByteSlice::from("email=") + &email,
+                    UserProfileField::Uid => ByteSlice::from(format!("uid={}", id)),
+                    UserProfileField::Role => ByteSlice::from("role=user"),
+                };
+        }
+
+        // Increment counter
+        *id += 1;
 
         // Encrypt
         let ciphertext = aes::ecb::encrypt(profile, &self.key);
@@ -82,10 +128,12 @@ impl Oracle for UserProfileOracle {
     }
 }
 
-impl UserProfileOracle {
+impl DecryptOracle for UserProfileOracle {
+    type Output = UserProfile;
+
     /// Decrypt a ciphertext that was encrypted by this oracle.
     /// The resulting plaintext will be parsed and returned as UserProfile
-    pub fn decrypt(&self, ciphertext: ByteSlice<'_>) -> Result<UserProfile, OracleError> {
+    fn decrypt(&self, ciphertext: ByteSlice<'_>) -> Result<UserProfile, OracleError> {
         // Decrypt using ECB mode and same key
         let plaintext = aes::ecb::decrypt(ciphertext, &self.key).change_context(OracleError::InvalidCiphertext)?;
 
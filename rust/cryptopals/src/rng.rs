@@ -0,0 +1,19 @@
+//! Pseudorandom number generators
+//!
+//! The generators implement `rand::RngCore`, so they can be used anywhere a `rand::Rng` is expected.
+//!
+//! ## Examples
+//! ```
+//! use cryptopals::{aes, rng::Mt19937};
+//!
+//! let mut rng = Mt19937::new(5489);
+//!
+//! // Generate a (predictable) AES key
+//! let key = aes::Key::with_random_values(&mut rng);
+//! ```
+pub use mt19937::{
+    Mt19937, STATE_LENGTH, TEMPERING_MASK_B, TEMPERING_MASK_C, TEMPERING_SHIFT_L, TEMPERING_SHIFT_S, TEMPERING_SHIFT_T,
+    TEMPERING_SHIFT_U,
+};
+
+mod mt19937;
@@ -0,0 +1,151 @@
+use rand::{RngCore, SeedableRng};
+
+/// Number of 32-bit words in the internal state
+pub const STATE_LENGTH: usize = 624;
+
+// Constants of the MT19937 recurrence
+const MIDDLE_OFFSET: usize = 397;
+const MATRIX_A: u32 = 0x9908b0df;
+const UPPER_MASK: u32 = 0x80000000;
+const LOWER_MASK: u32 = 0x7fffffff;
+const INITIALIZATION_MULTIPLIER: u32 = 1812433253;
+
+/// Right shift of the first tempering step
+pub const TEMPERING_SHIFT_U: u32 = 11;
+/// Left shift of the second tempering step
+pub const TEMPERING_SHIFT_S: u32 = 7;
+/// Mask of the second tempering step
+pub const TEMPERING_MASK_B: u32 = 0x9d2c5680;
+/// Left shift of the third tempering step
+pub const TEMPERING_SHIFT_T: u32 = 15;
+/// Mask of the third tempering step
+pub const TEMPERING_MASK_C: u32 = 0xefc60000;
+/// Right shift of the last tempering step
+pub const TEMPERING_SHIFT_L: u32 = 18;
+
+/// The 32-bit Mersenne Twister (MT19937)
+///
+/// ## Examples
+/// ```
+/// use cryptopals::rng::Mt19937;
+/// use rand::RngCore;
+///
+/// let mut rng = Mt19937::new(5489);
+///
+/// assert_eq!(rng.next_u32(), 3499211612);
+/// assert_eq!(rng.next_u32(), 581869302);
+/// ```
+#[derive(Clone)]
+pub struct Mt19937 {
+    state: [u32; STATE_LENGTH],
+    index: usize,
+}
+
+impl Mt19937 {
+    /// Initialize the generator from a seed
+    pub fn new(seed: u32) -> Mt19937 {
+        let mut state = [0; STATE_LENGTH];
+        state[0] = seed;
+
+        // Fill the remaining state from the previous value
+        for index in 1..STATE_LENGTH {
+            let previous = state[index - 1];
+            state[index] = INITIALIZATION_MULTIPLIER
+                .wrapping_mul(previous ^ (previous >> 30))
+                .wrapping_add(index as u32);
+        }
+
+        // Force a twist before the first output
+        Mt19937 {
+            state,
+            index: STATE_LENGTH,
+        }
+    }
+
+    /// Initialize the generator from an internal state of which every word has already been output.
+    ///
+    /// The next output will be generated from the twisted state.
+    pub fn from_state(state: [u32; STATE_LENGTH]) -> Mt19937 {
+        Mt19937 {
+            state,
+            index: STATE_LENGTH,
+        }
+    }
+
+    /// Generate the next batch of internal state
+    fn twist(&mut self) {
+        for index in 0..STATE_LENGTH {
+            // Combine the upper bit of the current word with the lower bits of the next word
+            let value = (self.state[index] & UPPER_MASK) | (self.state[(index + 1) % STATE_LENGTH] & LOWER_MASK);
+
+            // Multiply by matrix A
+            let mut value_a = value >> 1;
+            if value & 1 == 1 {
+                value_a ^= MATRIX_A;
+            }
+
+            self.state[index] = self.state[(index + MIDDLE_OFFSET) % STATE_LENGTH] ^ value_a;
+        }
+
+        self.index = 0;
+    }
+}
+
+// Apply the tempering transform to a word of the internal state
+fn temper(mut value: u32) -> u32 {
+    value ^= value >> TEMPERING_SHIFT_U;
+    value ^= (value << TEMPERING_SHIFT_S) & TEMPERING_MASK_B;
+    value ^= (value << TEMPERING_SHIFT_T) & TEMPERING_MASK_C;
+    value ^= value >> TEMPERING_SHIFT_L;
+
+    value
+}
+
+impl RngCore for Mt19937 {
+    fn next_u32(&mut self) -> u32 {
+        // Generate new state once all words have been used
+        if self.index >= STATE_LENGTH {
+            self.twist();
+        }
+
+        let value = self.state[self.index];
+        self.index += 1;
+
+        temper(value)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        // Use the first output for the lower half
+        let lower = self.next_u32() as u64;
+        let upper = self.next_u32() as u64;
+
+        (upper << 32) | lower
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        // Use one output for every 4 bytes
+        for chunk in dest.chunks_mut(4) {
+            let bytes = self.next_u32().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+
+        Ok(())
+    }
+}
+
+impl SeedableRng for Mt19937 {
+    type Seed = [u8; 4];
+
+    fn from_seed(seed: Self::Seed) -> Mt19937 {
+        Mt19937::new(u32::from_le_bytes(seed))
+    }
+
+    fn seed_from_u64(seed: u64) -> Mt19937 {
+        // MT19937 only uses a 32-bit seed
+        Mt19937::new(seed as u32)
+    }
+}
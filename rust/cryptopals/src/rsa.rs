@@ -0,0 +1,27 @@
+//! Rivest–Shamir–Adleman (RSA) public-key cryptography
+//!
+//! Encryption uses raw ("textbook") RSA, without any padding. Messages are converted from a `ByteSlice` to a big
+//! integer and back using the big-endian byte order. Signatures use PKCS#1 v1.5 padding.
+//!
+//! ## Examples
+//! ```
+//! use cryptopals::{byte::*, rsa};
+//! use num_bigint::BigUint;
+//!
+//! let mut rng = rand::thread_rng();
+//! let private_key = rsa::PrivateKey::generate(256, &mut rng);
+//! let public_key = private_key.public_key();
+//!
+//! let plaintext = ByteSlice::from("cryptopals");
+//! let ciphertext = public_key.encrypt(&BigUint::from(&plaintext));
+//!
+//! assert_eq!(ByteSlice::from(&private_key.decrypt(&ciphertext)), plaintext);
+//! ```
+pub use digest_info::{encode_digest_info, DigestAlgorithm};
+pub use key::{PrivateKey, PublicKey, PUBLIC_EXPONENT};
+pub use prime::{generate_prime, is_probable_prime};
+
+mod digest_info;
+mod key;
+mod pkcs1;
+mod prime;
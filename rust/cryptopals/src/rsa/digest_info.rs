@@ -0,0 +1,158 @@
+use crate::{
+    byte::*,
+    hash::{Hash, Md4, Sha1, Sha256},
+};
+
+// Tags of the ASN.1 types that occur in a DigestInfo
+const TAG_SEQUENCE: u8 = 0x30;
+const TAG_OBJECT_IDENTIFIER: u8 = 0x06;
+const TAG_NULL: u8 = 0x05;
+const TAG_OCTET_STRING: u8 = 0x04;
+
+/// A hash function that can be identified in a PKCS#1 v1.5 signature
+pub trait DigestAlgorithm: Hash {
+    /// Arcs of the ASN.1 object identifier of the hash function
+    const OBJECT_IDENTIFIER: &'static [u32];
+}
+
+impl DigestAlgorithm for Md4 {
+    const OBJECT_IDENTIFIER: &'static [u32] = &[1, 2, 840, 113549, 2, 4];
+}
+
+impl DigestAlgorithm for Sha1 {
+    const OBJECT_IDENTIFIER: &'static [u32] = &[1, 3, 14, 3, 2, 26];
+}
+
+impl DigestAlgorithm for Sha256 {
+    const OBJECT_IDENTIFIER: &'static [u32] = &[2, 16, 840, 1, 101, 3, 4, 2, 1];
+}
+
+/// Encode a digest as an ASN.1 DigestInfo using the distinguished encoding rules (DER), as defined in RFC 8017
+///
+/// The DigestInfo is a sequence of the algorithm identifier of the hash function and the digest itself:
+/// ```text
+/// DigestInfo ::= SEQUENCE {
+///     digestAlgorithm SEQUENCE { algorithm OBJECT IDENTIFIER, parameters NULL },
+///     digest OCTET STRING
+/// }
+/// ```
+///
+/// ## Examples
+/// ```
+/// use cryptopals::{byte::*, hash::{Hash, Sha1}, rsa};
+/// use byte_encoding_macro::hex;
+///
+/// let digest = Sha1::digest(&ByteSlice::from("abc"));
+/// let digest_info = rsa::encode_digest_info::<Sha1>(&digest);
+///
+/// // The DigestInfo of SHA-1 always starts with the same bytes, which are listed in RFC 8017
+/// let prefix = ByteSlice::from(hex!("3021300906052b0e03021a05000414").as_ref());
+/// assert_eq!(digest_info, prefix + digest);
+/// ```
+pub fn encode_digest_info<H: DigestAlgorithm>(digest: &ByteSlice<'_>) -> ByteSlice<'static> {
+    let algorithm = [
+        encode(TAG_OBJECT_IDENTIFIER, encode_object_identifier(H::OBJECT_IDENTIFIER)),
+        encode(TAG_NULL, Vec::new()),
+    ]
+    .concat();
+
+    let digest_info = [
+        encode(TAG_SEQUENCE, algorithm),
+        encode(TAG_OCTET_STRING, digest.iter().copied().collect()),
+    ]
+    .concat();
+
+    ByteSlice::from(encode(TAG_SEQUENCE, digest_info))
+}
+
+// Encode the tag, length and value of a single ASN.1 type
+fn encode(tag: u8, value: Vec<u8>) -> Vec<u8> {
+    let mut bytes = vec![tag];
+
+    // Short lengths fit in a single byte, longer lengths are prefixed with the number of length bytes
+    let length = value.len();
+    if length < 0x80 {
+        bytes.push(length as u8);
+    } else {
+        let length_bytes = length
+            .to_be_bytes()
+            .into_iter()
+            .skip_while(|byte| *byte == 0)
+            .collect::<Vec<_>>();
+        bytes.push(0x80 | length_bytes.len() as u8);
+        bytes.extend(length_bytes);
+    }
+
+    bytes.extend(value);
+    bytes
+}
+
+// Encode the arcs of an object identifier, where the first two arcs are combined into a single value. Each value is
+// encoded in base 128, since it might not fit in a single byte.
+//
+// Panics if the object identifier has fewer than two arcs, or if the first two arcs are out of range.
+fn encode_object_identifier(arcs: &[u32]) -> Vec<u8> {
+    let [first, second, remainder @ ..] = arcs else {
+        panic!("object identifier should have at least two arcs");
+    };
+    assert!(*first <= 2, "first arc of object identifier should be 0, 1 or 2");
+    assert!(*first == 2 || *second < 40, "second arc of object identifier should be below 40");
+
+    let combined = 40 * first + second;
+    [combined]
+        .iter()
+        .chain(remainder)
+        .flat_map(|arc| {
+            // The last group of 7 bits is the only one without the continuation bit
+            let mut groups = vec![(arc & 0x7f) as u8];
+            let mut remainder = arc >> 7;
+            while remainder > 0 {
+                groups.push(0x80 | (remainder & 0x7f) as u8);
+                remainder >>= 7;
+            }
+
+            groups.into_iter().rev()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use byte_encoding_macro::hex;
+
+    #[test]
+    fn md4_prefix() {
+        let digest = ByteSlice::with_repeated_byte_and_length(Md4::DIGEST_LENGTH, 0);
+        let prefix = hex!("3020300c06082a864886f70d020405000410");
+
+        assert_eq!(encode_digest_info::<Md4>(&digest), ByteSlice::from(prefix.as_ref()) + digest);
+    }
+
+    #[test]
+    fn sha256_prefix() {
+        let digest = ByteSlice::with_repeated_byte_and_length(Sha256::DIGEST_LENGTH, 0);
+        let prefix = hex!("3031300d060960864801650304020105000420");
+
+        assert_eq!(encode_digest_info::<Sha256>(&digest), ByteSlice::from(prefix.as_ref()) + digest);
+    }
+
+    #[test]
+    fn large_first_arcs() {
+        // Example of X.690, where the combined first arcs need two bytes
+        assert_eq!(encode_object_identifier(&[2, 999, 3]), [0x88, 0x37, 0x03]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn single_arc() {
+        encode_object_identifier(&[1]);
+    }
+
+    #[test]
+    fn long_form_length() {
+        let value = vec![0; 300];
+
+        assert_eq!(encode(TAG_OCTET_STRING, value)[..4], [TAG_OCTET_STRING, 0x82, 0x01, 0x2c]);
+    }
+}
@@ -0,0 +1,82 @@
+use super::generate_prime;
+use crate::number_theory::modular_inverse;
+use num_bigint::BigUint;
+
+/// The public exponent `e` of every generated key
+pub const PUBLIC_EXPONENT: u32 = 3;
+
+/// The public part of an RSA key, consisting of the modulus `n` and the public exponent `e`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PublicKey {
+    n: BigUint,
+    e: BigUint,
+}
+
+impl PublicKey {
+    /// Create a public key from a modulus and public exponent
+    pub fn new(n: BigUint, e: BigUint) -> PublicKey {
+        PublicKey { n, e }
+    }
+
+    /// Return the modulus
+    pub fn n(&self) -> &BigUint {
+        &self.n
+    }
+
+    /// Return the public exponent
+    pub fn e(&self) -> &BigUint {
+        &self.e
+    }
+
+    /// Return the number of bytes of the modulus
+    pub fn length(&self) -> usize {
+        self.n.bits().div_ceil(8) as usize
+    }
+
+    /// Raw RSA encryption `c = m^e mod n`, where the message should be smaller than the modulus
+    pub fn encrypt(&self, message: &BigUint) -> BigUint {
+        message.modpow(&self.e, &self.n)
+    }
+}
+
+/// An RSA key pair, consisting of the modulus `n`, public exponent `e` and private exponent `d`
+#[derive(Clone, Debug)]
+pub struct PrivateKey {
+    public_key: PublicKey,
+    d: BigUint,
+}
+
+impl PrivateKey {
+    /// Generate a random key pair where the modulus has (about) the given number of bits
+    pub fn generate(bits: u64, rng: &mut impl rand::Rng) -> PrivateKey {
+        let e = BigUint::from(PUBLIC_EXPONENT);
+
+        loop {
+            let p = generate_prime(bits / 2, rng);
+            let q = generate_prime(bits - bits / 2, rng);
+            if p == q {
+                continue;
+            }
+
+            // The public exponent is only invertible if it's coprime with the totient
+            let totient = (&p - 1_u32) * (&q - 1_u32);
+            let Ok(d) = modular_inverse(&e, &totient) else {
+                continue;
+            };
+
+            let public_key = PublicKey::new(p * q, e);
+
+            return PrivateKey { public_key, d };
+        }
+    }
+
+    /// Return the public part of the key pair
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+
+    /// Raw RSA decryption `m = c^d mod n`
+    pub fn decrypt(&self, ciphertext: &BigUint) -> BigUint {
+        ciphertext.modpow(&self.d, self.public_key.n())
+    }
+}
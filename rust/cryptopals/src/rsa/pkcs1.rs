@@ -0,0 +1,120 @@
+use super::{encode_digest_info, DigestAlgorithm, PrivateKey, PublicKey};
+use crate::{byte::*, CryptopalsError};
+use error_stack::Result;
+use num_bigint::BigUint;
+
+// Minimum number of 0xff bytes in the padding of a signature block
+const MINIMUM_PADDING_LENGTH: usize = 8;
+
+impl PrivateKey {
+    /// Sign the digest of a message using PKCS#1 v1.5 padding
+    ///
+    /// The signature block `00 01 FF .. FF 00 DigestInfo` has the same length as the modulus, and is decrypted with the
+    /// private key.
+    ///
+    /// ## Examples
+    /// ```
+    /// use cryptopals::{byte::*, hash::Sha1, rsa};
+    ///
+    /// let private_key = rsa::PrivateKey::generate(512, &mut rand::thread_rng());
+    /// let signature = private_key.sign::<Sha1>(&ByteSlice::from("hi mom"))?;
+    ///
+    /// assert!(private_key.public_key().verify::<Sha1>(&ByteSlice::from("hi mom"), &signature));
+    /// assert!(!private_key.public_key().verify::<Sha1>(&ByteSlice::from("hi dad"), &signature));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn sign<H: DigestAlgorithm>(&self, message: &ByteSlice<'_>) -> Result<ByteSlice<'static>, CryptopalsError> {
+        let length = self.public_key().length();
+        let block = encode_signature_block::<H>(message, length)?;
+
+        let signature = self.decrypt(&BigUint::from(&block));
+
+        Ok(left_pad(&signature, length))
+    }
+}
+
+impl PublicKey {
+    /// Verify a PKCS#1 v1.5 signature, by checking that the whole signature block equals the expected one
+    pub fn verify<H: DigestAlgorithm>(&self, message: &ByteSlice<'_>, signature: &ByteSlice<'_>) -> bool {
+        let length = self.length();
+        if signature.length() != length {
+            return false;
+        }
+
+        let Some(block) = self.encrypt_signature(signature) else {
+            return false;
+        };
+
+        match encode_signature_block::<H>(message, length) {
+            Ok(expected_block) => block == expected_block,
+            Err(_) => false,
+        }
+    }
+
+    /// Verify a PKCS#1 v1.5 signature the wrong way, by scanning the signature block from left to right.
+    ///
+    /// This verifier accepts any number of 0xff bytes in the padding, and ignores any bytes after the digest. Therefore,
+    /// the digest does not need to be right-justified.
+    pub fn verify_sloppy<H: DigestAlgorithm>(&self, message: &ByteSlice<'_>, signature: &ByteSlice<'_>) -> bool {
+        let Some(block) = self.encrypt_signature(signature) else {
+            return false;
+        };
+        let block = block.iter().copied().collect::<Vec<_>>();
+
+        // Scan for 00 01 FF .. FF 00
+        let Some(remainder) = block.strip_prefix(&[0x00, 0x01]) else {
+            return false;
+        };
+        let padding_length = remainder.iter().take_while(|byte| **byte == 0xff).count();
+        let Some(remainder) = remainder[padding_length..].strip_prefix(&[0x00]) else {
+            return false;
+        };
+
+        // Compare the DigestInfo, but ignore whatever comes after it
+        let digest_info = encode_digest_info::<H>(&H::digest(message))
+            .iter()
+            .copied()
+            .collect::<Vec<_>>();
+
+        remainder.starts_with(&digest_info)
+    }
+
+    // Encrypt the signature with the public key to obtain the signature block, if the signature is smaller than the
+    // modulus
+    fn encrypt_signature(&self, signature: &ByteSlice<'_>) -> Option<ByteSlice<'static>> {
+        let signature = BigUint::from(signature);
+        if signature >= *self.n() {
+            return None;
+        }
+
+        Some(left_pad(&self.encrypt(&signature), self.length()))
+    }
+}
+
+// Build the signature block `00 01 FF .. FF 00 DigestInfo` of the given length
+fn encode_signature_block<H: DigestAlgorithm>(
+    message: &ByteSlice<'_>,
+    length: usize,
+) -> Result<ByteSlice<'static>, CryptopalsError> {
+    let digest_info = encode_digest_info::<H>(&H::digest(message));
+
+    // The modulus should be long enough to fit the DigestInfo and the minimal padding
+    let padding_length = length
+        .checked_sub(digest_info.length() + 3)
+        .filter(|padding_length| *padding_length >= MINIMUM_PADDING_LENGTH)
+        .ok_or(CryptopalsError::InvalidLength)?;
+
+    let block = ByteSlice::from([0x00, 0x01].as_ref())
+        + ByteSlice::with_repeated_byte_and_length(padding_length, 0xff)
+        + ByteSlice::from([0x00].as_ref())
+        + digest_info;
+
+    Ok(block)
+}
+
+// Convert an integer to bytes in big-endian, with leading zeroes up to the given length
+fn left_pad(value: &BigUint, length: usize) -> ByteSlice<'static> {
+    let bytes = ByteSlice::from(value);
+
+    ByteSlice::with_repeated_byte_and_length(length.saturating_sub(bytes.length()), 0) + bytes
+}
@@ -0,0 +1,79 @@
+use num_bigint::{BigUint, RandBigInt};
+use num_traits::{One, Zero};
+
+// Number of rounds of the Miller–Rabin test, which gives an error probability of at most 4^-40
+const MILLER_RABIN_ROUNDS: usize = 40;
+
+// Small primes to quickly rule out most candidates
+const SMALL_PRIMES: [u32; 15] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47];
+
+/// Test whether a number is (very likely) prime using the Miller–Rabin primality test
+///
+/// ## Examples
+/// ```
+/// use cryptopals::rsa;
+/// use num_bigint::BigUint;
+///
+/// let mut rng = rand::thread_rng();
+///
+/// assert!(rsa::is_probable_prime(&BigUint::from(7919_u32), &mut rng));
+/// assert!(!rsa::is_probable_prime(&BigUint::from(7917_u32), &mut rng));
+/// ```
+pub fn is_probable_prime(n: &BigUint, rng: &mut impl rand::Rng) -> bool {
+    // Trial division by small primes
+    for small_prime in SMALL_PRIMES {
+        let small_prime = BigUint::from(small_prime);
+        if *n == small_prime {
+            return true;
+        }
+        if (n % &small_prime).is_zero() {
+            return false;
+        }
+    }
+
+    // Zero and one are not prime
+    if *n < BigUint::from(2_u32) {
+        return false;
+    }
+
+    // Write n - 1 as 2^s * d with d odd
+    let n_minus_one = n - 1_u32;
+    let s = n_minus_one.trailing_zeros().unwrap_or(0);
+    let d = &n_minus_one >> s;
+
+    'witness: for _ in 0..MILLER_RABIN_ROUNDS {
+        let a = rng.gen_biguint_range(&BigUint::from(2_u32), &n_minus_one);
+        let mut x = a.modpow(&d, n);
+
+        if x.is_one() || x == n_minus_one {
+            continue;
+        }
+
+        for _ in 1..s {
+            x = x.modpow(&BigUint::from(2_u32), n);
+            if x == n_minus_one {
+                continue 'witness;
+            }
+        }
+
+        // The witness proves that n is composite
+        return false;
+    }
+
+    true
+}
+
+/// Generate a random prime number with exactly the given number of bits
+pub fn generate_prime(bits: u64, rng: &mut impl rand::Rng) -> BigUint {
+    loop {
+        let mut candidate = rng.gen_biguint(bits);
+
+        // Set the highest bit to get the right size, and the lowest bit to get an odd number
+        candidate.set_bit(bits - 1, true);
+        candidate.set_bit(0, true);
+
+        if is_probable_prime(&candidate, rng) {
+            return candidate;
+        }
+    }
+}
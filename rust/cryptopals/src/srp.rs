@@ -0,0 +1,62 @@
+//! Secure Remote Password (SRP) protocol
+//!
+//! A client proves to a server that it knows a password, while the server only stores a salted verifier `v = g^x mod N`
+//! of the password. Both parties derive the same session key without ever sending the password, after which the client
+//! sends an HMAC-SHA256 of the salt as proof of the session key.
+//!
+//! ## Examples
+//! ```
+//! use cryptopals::{dh, srp};
+//! use std::thread;
+//!
+//! let mut server = srp::Server::new(dh::Parameters::default());
+//! server.register("alice@example.com", "correct horse battery staple");
+//!
+//! let (client_channel, server_channel) = srp::channel();
+//! let server = thread::spawn(move || server.accept(&server_channel));
+//!
+//! let client = srp::Client::new(dh::Parameters::default(), "alice@example.com", "correct horse battery staple");
+//! assert!(client.login(&client_channel)?);
+//! assert!(server.join().unwrap()?);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+pub use channel::{channel, Channel, Message};
+pub use client::Client;
+pub use server::Server;
+
+use crate::{
+    byte::*,
+    hash::{hmac, Hash, Sha256},
+};
+use num_bigint::BigUint;
+
+mod channel;
+mod client;
+mod server;
+
+// Multiplier parameter `k` of SRP-6
+const MULTIPLIER: u32 = 3;
+
+/// Calculate the private key `x = SHA256(salt || password)` from which the verifier is derived
+pub fn hash_password(salt: &ByteSlice<'_>, password: &str) -> BigUint {
+    let digest = Sha256::digest(&(salt + ByteSlice::from(password)));
+
+    BigUint::from(&digest)
+}
+
+/// Derive the session key `K = SHA256(S)` from the shared secret
+pub fn derive_key(shared_secret: &BigUint) -> ByteSlice<'static> {
+    Sha256::digest(&ByteSlice::from(shared_secret))
+}
+
+/// Calculate the proof `HMAC-SHA256(K, salt)` which the client sends to show that it knows the session key
+pub fn prove_key(key: &ByteSlice<'_>, salt: &ByteSlice<'_>) -> ByteSlice<'static> {
+    hmac::<Sha256>(key, salt)
+}
+
+// Calculate the scrambling parameter `u = SHA256(A || B)` of SRP-6
+fn scramble(client_public_key: &BigUint, server_public_key: &BigUint) -> BigUint {
+    let digest = Sha256::digest(&(ByteSlice::from(client_public_key) + ByteSlice::from(server_public_key)));
+
+    BigUint::from(&digest)
+}
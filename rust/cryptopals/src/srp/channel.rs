@@ -0,0 +1,87 @@
+use crate::{byte::*, CryptopalsError};
+use error_stack::{report, Result};
+use num_bigint::BigUint;
+use std::sync::mpsc::{self, Receiver, Sender};
+
+/// A message of the login protocol
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Message {
+    /// Email address and public key `A = g^a mod N` of the client
+    Login {
+        /// Email address with which the user was registered
+        email: String,
+        /// Public key of the client
+        public_key: BigUint,
+    },
+    /// Salt of the user and public key `B = kv + g^b mod N` of the server
+    Challenge {
+        /// Salt with which the verifier was derived
+        salt: ByteSlice<'static>,
+        /// Public key of the server
+        public_key: BigUint,
+    },
+    /// Salt of the user, public key `B = g^b mod N` of the server and a random scrambling parameter `u`, as used by
+    /// simplified SRP
+    SimplifiedChallenge {
+        /// Salt with which the verifier was derived
+        salt: ByteSlice<'static>,
+        /// Public key of the server
+        public_key: BigUint,
+        /// Random scrambling parameter
+        scrambler: BigUint,
+    },
+    /// Proof of the session key, which is an HMAC-SHA256 of the salt
+    Proof(ByteSlice<'static>),
+    /// Whether the server accepted the login
+    LoginResult(bool),
+}
+
+/// One end of an in-memory, bidirectional channel
+pub struct Channel {
+    sender: Sender<Message>,
+    receiver: Receiver<Message>,
+}
+
+/// Create a pair of connected channels, such that messages sent on one end are received on the other
+///
+/// ## Examples
+/// ```
+/// use cryptopals::srp;
+///
+/// let (client, server) = srp::channel();
+///
+/// server.send(srp::Message::LoginResult(false))?;
+/// assert_eq!(client.receive()?, srp::Message::LoginResult(false));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn channel() -> (Channel, Channel) {
+    let (lhs_sender, rhs_receiver) = mpsc::channel();
+    let (rhs_sender, lhs_receiver) = mpsc::channel();
+
+    let lhs = Channel {
+        sender: lhs_sender,
+        receiver: lhs_receiver,
+    };
+    let rhs = Channel {
+        sender: rhs_sender,
+        receiver: rhs_receiver,
+    };
+
+    (lhs, rhs)
+}
+
+impl Channel {
+    /// Send a message to the other end of the channel
+    pub fn send(&self, message: Message) -> Result<(), CryptopalsError> {
+        self.sender
+            .send(message)
+            .map_err(|_| report!(CryptopalsError::ChannelClosed))
+    }
+
+    /// Wait for a message from the other end of the channel
+    pub fn receive(&self) -> Result<Message, CryptopalsError> {
+        self.receiver
+            .recv()
+            .map_err(|_| report!(CryptopalsError::ChannelClosed))
+    }
+}
@@ -0,0 +1,84 @@
+use super::{derive_key, hash_password, prove_key, scramble, Channel, Message, MULTIPLIER};
+use crate::{dh::Parameters, CryptopalsError};
+use error_stack::{bail, Result};
+use num_bigint::{BigUint, RandBigInt};
+
+/// A client which logs in to a server with an email address and password.
+///
+/// The login consists of the following messages:
+///  - the client sends its email address and public key `A`
+///  - the server sends the salt and its public key `B`, and in case of simplified SRP a scrambling parameter `u`
+///  - the client sends a proof of the session key
+///  - the server sends whether the login was accepted
+///
+/// The client supports both SRP-6 and simplified SRP, depending on the challenge of the server.
+pub struct Client {
+    parameters: Parameters,
+    email: String,
+    password: String,
+}
+
+impl Client {
+    /// Create a client for the given user, where `N` and `g` are the prime modulus and generator of the parameters
+    pub fn new(parameters: Parameters, email: &str, password: &str) -> Client {
+        Client {
+            parameters,
+            email: email.to_string(),
+            password: password.to_string(),
+        }
+    }
+
+    /// Log in to the server and return whether the login was accepted
+    pub fn login(&self, channel: &Channel) -> Result<bool, CryptopalsError> {
+        let mut rng = rand::thread_rng();
+        let (n, g) = (self.parameters.p(), self.parameters.g());
+
+        // Send a public key `A = g^a mod N`
+        let private_key = rng.gen_biguint_range(&BigUint::from(1_u32), n);
+        let public_key = g.modpow(&private_key, n);
+        channel.send(Message::Login {
+            email: self.email.clone(),
+            public_key: public_key.clone(),
+        })?;
+
+        let (salt, shared_secret) = match channel.receive()? {
+            Message::Challenge {
+                salt,
+                public_key: server_public_key,
+            } => {
+                let x = hash_password(&salt, &self.password);
+                let u = scramble(&public_key, &server_public_key);
+
+                // S = (B - k * g^x)^(a + u * x) mod N, where k * N is added to prevent underflow
+                let base = (server_public_key + MULTIPLIER * n - MULTIPLIER * g.modpow(&x, n)) % n;
+                let shared_secret = base.modpow(&(private_key + u * x), n);
+
+                (salt, shared_secret)
+            }
+            Message::SimplifiedChallenge {
+                salt,
+                public_key: server_public_key,
+                scrambler: u,
+            } => {
+                let x = hash_password(&salt, &self.password);
+
+                // S = B^(a + u * x) mod N
+                let shared_secret = server_public_key.modpow(&(private_key + u * x), n);
+
+                (salt, shared_secret)
+            }
+            // The server does not know the user
+            Message::LoginResult(false) => return Ok(false),
+            _ => bail!(CryptopalsError::UnexpectedMessage),
+        };
+
+        // Prove that both parties derived the same session key
+        channel.send(Message::Proof(prove_key(&derive_key(&shared_secret), &salt)))?;
+
+        let Message::LoginResult(is_accepted) = channel.receive()? else {
+            bail!(CryptopalsError::UnexpectedMessage);
+        };
+
+        Ok(is_accepted)
+    }
+}
@@ -0,0 +1,111 @@
+use super::{derive_key, hash_password, prove_key, scramble, Channel, Message, MULTIPLIER};
+use crate::{byte::*, dh::Parameters, CryptopalsError};
+use error_stack::{bail, Result};
+use num_bigint::{BigUint, RandBigInt};
+use std::collections::HashMap;
+
+// Number of bytes in the salt of a user
+const SALT_LENGTH: usize = 16;
+
+// Number of bits in the random scrambling parameter of simplified SRP
+const SCRAMBLER_BITS: u64 = 128;
+
+/// A server which stores a salt and verifier `v = g^x mod N` for each user, and accepts logins of clients.
+///
+/// See `Client` for the messages of the login protocol.
+pub struct Server {
+    parameters: Parameters,
+    is_simplified: bool,
+    users: HashMap<String, (ByteSlice<'static>, BigUint)>,
+}
+
+impl Server {
+    /// Create a server which uses SRP-6, where `N` and `g` are the prime modulus and generator of the parameters
+    pub fn new(parameters: Parameters) -> Server {
+        Server {
+            parameters,
+            is_simplified: false,
+            users: HashMap::new(),
+        }
+    }
+
+    /// Create a server which uses simplified SRP, where the public key of the server does not depend on the verifier and
+    /// the scrambling parameter is random
+    pub fn simplified(parameters: Parameters) -> Server {
+        Server {
+            is_simplified: true,
+            ..Server::new(parameters)
+        }
+    }
+
+    /// Register a user with a random salt, and only store the verifier of the password
+    pub fn register(&mut self, email: &str, password: &str) {
+        let mut rng = rand::thread_rng();
+
+        let salt = ByteSlice::with_random_values_and_length(SALT_LENGTH, &mut rng);
+        let x = hash_password(&salt, password);
+        let verifier = self.parameters.g().modpow(&x, self.parameters.p());
+
+        self.users.insert(email.to_string(), (salt, verifier));
+    }
+
+    /// Handle a single login of a client and return whether it was accepted
+    pub fn accept(&self, channel: &Channel) -> Result<bool, CryptopalsError> {
+        let mut rng = rand::thread_rng();
+        let (n, g) = (self.parameters.p(), self.parameters.g());
+
+        let Message::Login {
+            email,
+            public_key: client_public_key,
+        } = channel.receive()?
+        else {
+            bail!(CryptopalsError::UnexpectedMessage);
+        };
+
+        // Reject unknown users right away
+        let Some((salt, verifier)) = self.users.get(&email) else {
+            channel.send(Message::LoginResult(false))?;
+            return Ok(false);
+        };
+
+        let private_key = rng.gen_biguint_range(&BigUint::from(1_u32), n);
+        let u = match self.is_simplified {
+            true => {
+                // B = g^b mod N, and a random u
+                let public_key = g.modpow(&private_key, n);
+                let u = rng.gen_biguint(SCRAMBLER_BITS);
+                channel.send(Message::SimplifiedChallenge {
+                    salt: salt.clone(),
+                    public_key,
+                    scrambler: u.clone(),
+                })?;
+
+                u
+            }
+            false => {
+                // B = k * v + g^b mod N, and u = SHA256(A || B)
+                let public_key = (MULTIPLIER * verifier + g.modpow(&private_key, n)) % n;
+                let u = scramble(&client_public_key, &public_key);
+                channel.send(Message::Challenge {
+                    salt: salt.clone(),
+                    public_key,
+                })?;
+
+                u
+            }
+        };
+
+        // S = (A * v^u)^b mod N
+        let shared_secret = (client_public_key * verifier.modpow(&u, n) % n).modpow(&private_key, n);
+        let expected_proof = prove_key(&derive_key(&shared_secret), salt);
+
+        let Message::Proof(proof) = channel.receive()? else {
+            bail!(CryptopalsError::UnexpectedMessage);
+        };
+
+        let is_accepted = proof == expected_proof;
+        channel.send(Message::LoginResult(is_accepted))?;
+
+        Ok(is_accepted)
+    }
+}
@@ -1,10 +1,12 @@
 use cryptopals::{
-    adversary, aes,
+    adversary::{self, AdversaryError},
+    aes,
     byte::*,
-    oracle::{self, EcbFixedPostfixOracle, Oracle},
+    oracle::{self, DecryptOracle, EcbFixedPostfixOracle, Oracle, OracleError},
 };
+use error_stack::Result;
 // Test support
-use support::{from_base64, funky_music, TestFile};
+use support::{from_base64, funky_music, seeded_rng, TestFile};
 mod support;
 
 #[test]
@@ -33,9 +35,11 @@ fn challenge_10() {
 
 #[test]
 fn challenge_11() {
+    let mut rng = seeded_rng();
+
     // Re-run the test multiple times, since the oracle involves randomness
     for _ in 0..16 {
-        let oracle = oracle::RandomBlockModeOracle::default();
+        let oracle = oracle::RandomBlockModeOracle::with_rng(&mut rng);
 
         // Let the adversary attack the oracle
         let detected_mode = adversary::detect_aes_block_mode(&oracle).expect("adversary should be successful");
@@ -46,7 +50,7 @@ fn challenge_11() {
 
 #[test]
 fn challenge_12() {
-    let oracle = oracle::EcbFixedPostfixOracle::default();
+    let oracle = oracle::EcbFixedPostfixOracle::with_rng(&mut seeded_rng());
 
     // It is known that the oracle uses ECB, but verify anyway.
     let detected_mode = adversary::detect_aes_block_mode(&oracle).expect("adversary should be successful");
@@ -58,9 +62,48 @@ fn challenge_12() {
     assert_eq!(&fixed_postfix, oracle.postfix());
 }
 
+#[test]
+fn challenge_12_query_budget() {
+    let oracle = oracle::RecordingOracle::new(oracle::EcbFixedPostfixOracle::with_rng(&mut seeded_rng()));
+
+    // Let the adversary attack the oracle
+    let fixed_postfix = adversary::attack_ecb_fixed_postfix(&oracle).expect("adversary should be successful");
+    assert_eq!(&fixed_postfix, oracle.oracle().postfix());
+
+    // One query for the block with the unknown byte, and a few batches of likely byte values, per recovered byte
+    assert!(oracle.query_count() <= 4 * fixed_postfix.length());
+
+    // Replaying the serialized transcript should lead to the same result
+    let transcript =
+        oracle::Transcript::try_from(oracle.transcript().to_string().as_str()).expect("transcript should be valid");
+    let replay = oracle::ReplayOracle::new(transcript);
+    let replayed_postfix = adversary::attack_ecb_fixed_postfix(&replay).expect("adversary should be successful");
+    assert_eq!(replayed_postfix, fixed_postfix);
+}
+
+#[test]
+fn challenge_12_partial_postfix() {
+    let oracle = oracle::RecordingOracle::with_budget(oracle::EcbFixedPostfixOracle::with_rng(&mut seeded_rng()), 40);
+
+    // The oracle stops answering before the adversary is done
+    let report = adversary::attack_ecb_fixed_postfix(&oracle).expect_err("oracle should run out of budget");
+
+    // The bytes that were recovered should still be reported
+    let AdversaryError::PostfixRecoveryStalled(partial_postfix) = report.current_context() else {
+        panic!("adversary should report partial postfix");
+    };
+    assert!(partial_postfix.length() > 0);
+    assert!(oracle
+        .oracle()
+        .postfix()
+        .iter()
+        .zip(partial_postfix.iter())
+        .all(|(lhs, rhs)| lhs == rhs));
+}
+
 #[test]
 fn challenge_13() {
-    let oracle = oracle::UserProfileOracle::default();
+    let oracle = oracle::UserProfileOracle::with_rng(&mut seeded_rng());
 
     // It is known that the oracle uses ECB, but verify anyway.
     let detected_mode = adversary::detect_aes_block_mode(&oracle).expect("adversary should be successful");
@@ -76,7 +119,7 @@ fn challenge_13() {
     let user_profile = oracle
         .decrypt(ciphertext)
         .expect("oracle should be able to decrypt");
-    assert_eq!(user_profile.is_admin(), false);
+    assert!(!user_profile.is_admin());
 
     // Invalid email should give an error
     let email = ByteSlice::from("foo@bar.com&role=admin");
@@ -88,12 +131,58 @@ fn challenge_13() {
     let user_profile = oracle
         .decrypt(ciphertext)
         .expect("oracle should be able to decrypt");
-    assert_eq!(user_profile.is_admin(), true);
+    assert!(user_profile.is_admin());
+}
+
+#[test]
+fn challenge_13_field_orders() {
+    use oracle::UserProfileField::{Email, Role, Uid};
+
+    let mut rng = seeded_rng();
+
+    for field_order in [
+        [Email, Uid, Role],
+        [Uid, Email, Role],
+        [Email, Role, Uid],
+        [Uid, Role, Email],
+        [Role, Email, Uid],
+        [Role, Uid, Email],
+    ] {
+        let oracle = oracle::UserProfileOracle::with_field_order_and_rng(field_order, &mut rng);
+
+        // Describe the format of the profile, where the user ID starts at 1000
+        let mut template = adversary::TokenTemplate::default().disallow(b"&=");
+        for (index, field) in field_order.into_iter().enumerate() {
+            if index > 0 {
+                template = template.fixed("&");
+            }
+
+            template = match field {
+                Email => template.fixed("email=").controlled(),
+                Uid => template.fixed("uid=").variable(4),
+                Role => template.fixed("role=").substitute("user", "admin"),
+            };
+        }
+
+        let result = adversary::forge_cut_and_paste(&oracle, &template);
+
+        // The admin role can only be cut from the email address and pasted at the end, together with padding
+        if field_order[2] == Role {
+            let ciphertext = result.expect("adversary should be successful");
+            let user_profile = oracle
+                .decrypt(ciphertext)
+                .expect("oracle should be able to decrypt");
+            assert!(user_profile.is_admin());
+        } else {
+            let report = result.expect_err("adversary should be unable to plan");
+            assert_eq!(report.current_context(), &AdversaryError::UnableToPlanCutAndPaste);
+        }
+    }
 }
 
 #[test]
 fn challenge_14() {
-    let oracle = EcbFixedPostfixOracle::with_random_prefix();
+    let oracle = EcbFixedPostfixOracle::with_random_prefix_and_rng(&mut seeded_rng());
 
     // It is known that the oracle uses ECB, but verify anyway.
     let detected_mode = adversary::detect_aes_block_mode(&oracle).expect("adversary should be successful");
@@ -105,6 +194,46 @@ fn challenge_14() {
     assert_eq!(&fixed_postfix, oracle.postfix());
 }
 
+#[test]
+fn challenge_14_random_prefix_per_query() {
+    let oracle = EcbFixedPostfixOracle::with_random_prefix_per_query_and_rng(&mut seeded_rng());
+
+    // Even with a different prefix for every query, ECB can be detected
+    let detected_mode = adversary::detect_aes_block_mode(&oracle).expect("adversary should be successful");
+    assert_eq!(&detected_mode, &aes::BlockMode::Ecb);
+
+    // Let the adversary attack the oracle
+    let fixed_postfix = adversary::attack_ecb_fixed_postfix(&oracle).expect("adversary should be successful");
+
+    assert_eq!(&fixed_postfix, oracle.postfix());
+}
+
+// Oracle which appends every byte value to the plaintext, in order to test postfixes which are not printable
+struct BinaryPostfixOracle(aes::Key);
+
+impl Default for BinaryPostfixOracle {
+    fn default() -> Self {
+        BinaryPostfixOracle(aes::Key::with_random_values(&mut seeded_rng()))
+    }
+}
+
+impl Oracle for BinaryPostfixOracle {
+    fn encrypt(&self, plaintext: ByteSlice<'_>) -> Result<ByteSlice<'static>, OracleError> {
+        let postfix = ByteSlice::from_iter(0..=255);
+        Ok(aes::ecb::encrypt(plaintext + postfix, &self.0))
+    }
+}
+
+#[test]
+fn challenge_14_binary_postfix() {
+    let oracle = BinaryPostfixOracle::default();
+
+    // Let the adversary attack the oracle
+    let fixed_postfix = adversary::attack_ecb_fixed_postfix(&oracle).expect("adversary should be successful");
+
+    assert_eq!(fixed_postfix, ByteSlice::from_iter(0..=255));
+}
+
 #[test]
 fn challenge_15() {
     // Verify valid padding
@@ -130,7 +259,7 @@ fn challenge_15() {
 
 #[test]
 fn challenge_16() {
-    let oracle = oracle::UserDataOracle::default();
+    let oracle = oracle::UserDataOracle::with_rng(&mut seeded_rng());
 
     // It is known that the oracle uses ECB, but verify anyway.
     let detected_mode = adversary::detect_aes_block_mode(&oracle).expect("adversary should be successful");
@@ -140,4 +269,33 @@ fn challenge_16() {
     let plaintext = ByteSlice::from(";admin=true;");
     let result = oracle.encrypt(plaintext);
     assert!(result.is_err());
+
+    // Inject the admin marker by flipping bits of the preceding block instead
+    let target = ByteSlice::from(";admin=true;");
+    let ciphertext =
+        adversary::forge_cbc_bit_flip(&oracle, &target, aes::BLOCK_LENGTH).expect("adversary should be successful");
+    assert!(oracle
+        .decrypt(ciphertext)
+        .expect("ciphertext should be valid"));
+}
+
+#[test]
+fn challenge_16_injection_offsets() {
+    let oracle = oracle::UserDataOracle::with_rng(&mut seeded_rng());
+    let target = ByteSlice::from(";admin=true;");
+
+    // The prefix fills exactly two blocks, so the target string fits in a single block at the first few offsets
+    for offset in 0..2 * aes::BLOCK_LENGTH {
+        let result = adversary::forge_cbc_bit_flip(&oracle, &target, offset);
+
+        if offset % aes::BLOCK_LENGTH + target.length() <= aes::BLOCK_LENGTH {
+            let ciphertext = result.expect("adversary should be successful");
+            assert!(oracle
+                .decrypt(ciphertext)
+                .expect("ciphertext should be valid"));
+        } else {
+            let report = result.expect_err("target string should overlap the scrambled block");
+            assert_eq!(report.current_context(), &AdversaryError::InjectionOverlapsScrambledBlock);
+        }
+    }
 }
@@ -0,0 +1,185 @@
+use cryptopals::{adversary, aes, byte::*, oracle::CbcPaddingOracle, rng::Mt19937};
+use rand::RngCore;
+// Test support
+use support::{from_base64, seeded_rng, TestFile};
+mod support;
+
+#[test]
+fn challenge_17() {
+    // Re-run the test multiple times, since the oracle involves randomness
+    let mut rng = seeded_rng();
+    for _ in 0..8 {
+        let oracle = CbcPaddingOracle::with_rng(&mut rng);
+
+        // Let the adversary attack the oracle
+        let plaintext = adversary::attack_cbc_padding_oracle(&oracle, oracle.ciphertext(), oracle.iv())
+            .expect("adversary should be successful");
+
+        assert_eq!(&plaintext, oracle.plaintext());
+    }
+}
+
+#[test]
+fn challenge_18() {
+    // Input
+    let key = aes::Key::from(*b"YELLOW SUBMARINE");
+    let nonce = 0;
+    let ciphertext = from_base64("L77na/nrFsKvynd6HzOoG7GHTLXsTVu9qvY/2syLXzhPweyyMTJULu/6/kXX0KSvoOLSFQ==");
+
+    // Expected output
+    let plaintext = ByteSlice::from("Yo, VIP Let's kick it Ice, Ice, baby Ice, Ice, baby ");
+
+    // Verify both encrypt and decrypt
+    assert_eq!(aes::ctr::decrypt(ciphertext.clone(), &key, nonce), plaintext);
+    assert_eq!(aes::ctr::encrypt(plaintext, &key, nonce), ciphertext);
+}
+
+// Plaintexts of challenge 19, which are encrypted using the same key and nonce
+const CHALLENGE_19_PLAINTEXTS: [&str; 40] = [
+    "SSBoYXZlIG1ldCB0aGVtIGF0IGNsb3NlIG9mIGRheQ==",
+    "Q29taW5nIHdpdGggdml2aWQgZmFjZXM=",
+    "RnJvbSBjb3VudGVyIG9yIGRlc2sgYW1vbmcgZ3JleQ==",
+    "RWlnaHRlZW50aC1jZW50dXJ5IGhvdXNlcy4=",
+    "SSBoYXZlIHBhc3NlZCB3aXRoIGEgbm9kIG9mIHRoZSBoZWFk",
+    "T3IgcG9saXRlIG1lYW5pbmdsZXNzIHdvcmRzLA==",
+    "T3IgaGF2ZSBsaW5nZXJlZCBhd2hpbGUgYW5kIHNhaWQ=",
+    "UG9saXRlIG1lYW5pbmdsZXNzIHdvcmRzLA==",
+    "QW5kIHRob3VnaHQgYmVmb3JlIEkgaGFkIGRvbmU=",
+    "T2YgYSBtb2NraW5nIHRhbGUgb3IgYSBnaWJl",
+    "VG8gcGxlYXNlIGEgY29tcGFuaW9u",
+    "QXJvdW5kIHRoZSBmaXJlIGF0IHRoZSBjbHViLA==",
+    "QmVpbmcgY2VydGFpbiB0aGF0IHRoZXkgYW5kIEk=",
+    "QnV0IGxpdmVkIHdoZXJlIG1vdGxleSBpcyB3b3JuOg==",
+    "QWxsIGNoYW5nZWQsIGNoYW5nZWQgdXR0ZXJseTo=",
+    "QSB0ZXJyaWJsZSBiZWF1dHkgaXMgYm9ybi4=",
+    "VGhhdCB3b21hbidzIGRheXMgd2VyZSBzcGVudA==",
+    "SW4gaWdub3JhbnQgZ29vZCB3aWxsLA==",
+    "SGVyIG5pZ2h0cyBpbiBhcmd1bWVudA==",
+    "VW50aWwgaGVyIHZvaWNlIGdyZXcgc2hyaWxsLg==",
+    "V2hhdCB2b2ljZSBtb3JlIHN3ZWV0IHRoYW4gaGVycw==",
+    "V2hlbiB5b3VuZyBhbmQgYmVhdXRpZnVsLA==",
+    "U2hlIHJvZGUgdG8gaGFycmllcnM/",
+    "VGhpcyBtYW4gaGFkIGtlcHQgYSBzY2hvb2w=",
+    "QW5kIHJvZGUgb3VyIHdpbmdlZCBob3JzZS4=",
+    "VGhpcyBvdGhlciBoaXMgaGVscGVyIGFuZCBmcmllbmQ=",
+    "V2FzIGNvbWluZyBpbnRvIGhpcyBmb3JjZTs=",
+    "SGUgbWlnaHQgaGF2ZSB3b24gZmFtZSBpbiB0aGUgZW5kLA==",
+    "U28gc2Vuc2l0aXZlIGhpcyBuYXR1cmUgc2VlbWVkLA==",
+    "U28gZGFyaW5nIGFuZCBzd2VldCBoaXMgdGhvdWdodC4=",
+    "VGhpcyBvdGhlciBtYW4gSSBoYWQgZHJlYW1lZA==",
+    "QSBkcnVua2VuLCB2YWluLWdsb3Jpb3VzIGxvdXQu",
+    "SGUgaGFkIGRvbmUgbW9zdCBiaXR0ZXIgd3Jvbmc=",
+    "VG8gc29tZSB3aG8gYXJlIG5lYXIgbXkgaGVhcnQs",
+    "WWV0IEkgbnVtYmVyIGhpbSBpbiB0aGUgc29uZzs=",
+    "SGUsIHRvbywgaGFzIHJlc2lnbmVkIGhpcyBwYXJ0",
+    "SW4gdGhlIGNhc3VhbCBjb21lZHk7",
+    "SGUsIHRvbywgaGFzIGJlZW4gY2hhbmdlZCBpbiBoaXMgdHVybiw=",
+    "VHJhbnNmb3JtZWQgdXR0ZXJseTo=",
+    "QSB0ZXJyaWJsZSBiZWF1dHkgaXMgYm9ybi4=",
+];
+
+// Encrypt each plaintext using AES CTR mode with a random key and a fixed nonce of 0
+fn encrypt_with_fixed_nonce(plaintexts: &[ByteSlice<'static>]) -> Vec<ByteSlice<'static>> {
+    let key = aes::Key::with_random_values(&mut seeded_rng());
+
+    plaintexts
+        .iter()
+        .map(|plaintext| aes::ctr::encrypt(plaintext.clone(), &key, 0))
+        .collect()
+}
+
+// Verify the recovered plaintexts. Every column covered by enough ciphertexts should be recovered exactly, while the
+// last few columns are covered by too few ciphertexts for frequency analysis to pick the right keystream byte.
+fn verify_recovered_plaintexts(recovered_plaintexts: &[ByteSlice<'_>], plaintexts: &[ByteSlice<'_>]) {
+    const MINIMUM_RELIABLE_COVERAGE: usize = 4;
+
+    assert_eq!(recovered_plaintexts.len(), plaintexts.len());
+
+    let recovered_plaintexts = recovered_plaintexts
+        .iter()
+        .map(|plaintext| plaintext.iter().copied().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let plaintexts = plaintexts
+        .iter()
+        .map(|plaintext| plaintext.iter().copied().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    for (recovered_plaintext, plaintext) in recovered_plaintexts.iter().zip(&plaintexts) {
+        assert_eq!(recovered_plaintext.len(), plaintext.len());
+    }
+
+    let longest_length = plaintexts.iter().map(Vec::len).max().unwrap();
+    for column in 0..longest_length {
+        let coverage = plaintexts
+            .iter()
+            .filter(|plaintext| plaintext.len() > column)
+            .count();
+        if coverage < MINIMUM_RELIABLE_COVERAGE {
+            break;
+        }
+
+        for (recovered_plaintext, plaintext) in recovered_plaintexts.iter().zip(&plaintexts) {
+            assert_eq!(recovered_plaintext.get(column), plaintext.get(column));
+        }
+    }
+}
+
+#[test]
+fn challenge_19() {
+    let plaintexts = CHALLENGE_19_PLAINTEXTS.map(from_base64);
+    let ciphertexts = encrypt_with_fixed_nonce(&plaintexts);
+
+    // Let the adversary attack the ciphertexts
+    let recovered_plaintexts = adversary::attack_ctr_fixed_nonce(&ciphertexts).expect("adversary should be successful");
+
+    verify_recovered_plaintexts(&recovered_plaintexts, &plaintexts);
+}
+
+#[test]
+#[ignore = "requires data/20.txt from the challenge page"]
+fn challenge_20() {
+    let plaintexts = TestFile::new("../../data/20.txt")
+        .lines()
+        .map(from_base64)
+        .collect::<Vec<_>>();
+    let ciphertexts = encrypt_with_fixed_nonce(&plaintexts);
+
+    // Let the adversary attack the ciphertexts
+    let recovered_plaintexts = adversary::attack_ctr_fixed_nonce(&ciphertexts).expect("adversary should be successful");
+
+    verify_recovered_plaintexts(&recovered_plaintexts, &plaintexts);
+}
+
+#[test]
+fn challenge_21() {
+    // Reference outputs of MT19937 with the default seed
+    let mut rng = Mt19937::new(5489);
+    let expected = [
+        3499211612, 581869302, 3890346734, 3586334585, 545404204, 4161255391, 3922919429, 949333985, 2715962298,
+        1323567403,
+    ];
+
+    for value in expected {
+        assert_eq!(rng.next_u32(), value);
+    }
+
+    // The 10000th output is specified by the C++ standard
+    let value = (expected.len()..10_000).map(|_| rng.next_u32()).last();
+    assert_eq!(value, Some(4123659995));
+}
+
+#[test]
+fn challenge_23() {
+    let mut rng = Mt19937::new(rand::random());
+
+    // Observe outputs of the generator
+    let outputs = (0..624).map(|_| rng.next_u32()).collect::<Vec<_>>();
+
+    // Let the adversary clone the generator
+    let mut clone = adversary::clone_mt19937(&outputs).expect("adversary should be successful");
+
+    // The clone should predict all future outputs
+    for _ in 0..2_000 {
+        assert_eq!(clone.next_u32(), rng.next_u32());
+    }
+}
@@ -0,0 +1,172 @@
+use cryptopals::{
+    adversary, aes,
+    byte::*,
+    hash::{Md4, Sha1},
+    oracle::{
+        CbcKeyAsIvOracle, CtrEditOracle, DecryptOracle, HmacTimingLeakOracle, Oracle, SecretPrefixMacOracle,
+        UserDataOracle, VerifyOracle,
+    },
+};
+use std::{sync::Mutex, time::Duration};
+// Test support
+use support::{from_base64, funky_music, seeded_rng, TestFile};
+mod support;
+
+// Timing attacks are sensitive to noise, so they should not run at the same time as each other
+static TIMING_ATTACK: Mutex<()> = Mutex::new(());
+
+#[test]
+fn challenge_25() {
+    // The data file of challenge 25 is the ECB encrypted file of challenge 7
+    let key = aes::Key::from(*b"YELLOW SUBMARINE");
+    let document = from_base64(TestFile::new("../../data/7.txt").to_string());
+
+    let oracle = CtrEditOracle::from_ecb_ciphertext_and_rng(document, &key, &mut seeded_rng())
+        .expect("document should be valid");
+
+    // Let the adversary attack the oracle
+    let plaintext =
+        adversary::attack_ctr_edit_oracle(&oracle, oracle.ciphertext()).expect("adversary should be successful");
+
+    assert_eq!(&plaintext, oracle.plaintext());
+    assert_eq!(plaintext, funky_music());
+}
+
+#[test]
+fn challenge_26() {
+    let oracle = UserDataOracle::with_block_mode_and_rng(aes::BlockMode::Ctr, &mut seeded_rng());
+
+    // Verify that the oracle uses CTR mode
+    let detected_mode = adversary::detect_aes_block_mode(&oracle).expect("adversary should be successful");
+    assert_eq!(&detected_mode, &aes::BlockMode::Ctr);
+
+    // Invalid user data should give error
+    let target = ByteSlice::from(";admin=true;");
+    assert!(oracle.encrypt(target.clone()).is_err());
+
+    // Regular user data should not result in an admin
+    let ciphertext = oracle
+        .encrypt(ByteSlice::from("cryptopals"))
+        .expect("user data should be valid");
+    assert!(!oracle
+        .decrypt(ciphertext)
+        .expect("ciphertext should be valid"));
+
+    // Let the adversary inject the target string
+    let ciphertext = adversary::forge_ctr_bit_flip(&oracle, &target).expect("adversary should be successful");
+    assert!(oracle
+        .decrypt(ciphertext)
+        .expect("ciphertext should be valid"));
+}
+
+#[test]
+fn challenge_27() {
+    let oracle = CbcKeyAsIvOracle::with_rng(&mut seeded_rng());
+    let plaintext = ByteSlice::from("comment1=cooking%20MCs;userdata=foo;comment2=%20like%20a%20pound%20of%20bacon");
+    let ciphertext = oracle
+        .encrypt(plaintext.clone())
+        .expect("plaintext should be valid");
+
+    // Regular ciphertexts should be accepted
+    assert!(oracle.decrypt(ciphertext.clone()).is_ok());
+
+    // Let the adversary recover the key
+    let key = adversary::attack_cbc_key_as_iv(&oracle).expect("adversary should be successful");
+
+    // The key (and IV) should decrypt the ciphertext
+    let decrypted = aes::cbc::decrypt_with_iv(ciphertext, &aes::Key::from(key.clone()), &key);
+    assert_eq!(decrypted.expect("key should be correct"), plaintext);
+}
+
+#[test]
+fn challenge_28() {
+    let oracle = SecretPrefixMacOracle::<Sha1>::with_rng(&mut seeded_rng());
+    let message = ByteSlice::from("comment1=cooking%20MCs;userdata=foo;comment2=%20like%20a%20pound%20of%20bacon");
+
+    // The MAC should be valid for the original message
+    let mac = oracle.sign(&message);
+    assert!(oracle.verify(&message, &mac));
+
+    // Tampering with the message should invalidate the MAC
+    let tampered_message =
+        ByteSlice::from("comment1=cooking%20MCs;userdata=foo;comment2=%20like%20a%20pound%20of%20bacoN");
+    assert!(!oracle.verify(&tampered_message, &mac));
+}
+
+#[test]
+fn challenge_29() {
+    let oracle = SecretPrefixMacOracle::<Sha1>::with_rng(&mut seeded_rng());
+    let message = ByteSlice::from("comment1=cooking%20MCs;userdata=foo;comment2=%20like%20a%20pound%20of%20bacon");
+    let mac = oracle.sign(&message);
+
+    // Let the adversary extend the message
+    let extension = ByteSlice::from(";admin=true");
+    let (forged_message, forged_mac) =
+        adversary::forge_length_extension_mac::<Sha1, _>(&oracle, &message, &mac, &extension)
+            .expect("adversary should be successful");
+
+    assert!(forged_message.to_string().ends_with(";admin=true"));
+    assert!(oracle.verify(&forged_message, &forged_mac));
+}
+
+#[test]
+fn challenge_30() {
+    let oracle = SecretPrefixMacOracle::<Md4>::with_rng(&mut seeded_rng());
+    let message = ByteSlice::from("comment1=cooking%20MCs;userdata=foo;comment2=%20like%20a%20pound%20of%20bacon");
+    let mac = oracle.sign(&message);
+
+    // Let the adversary extend the message
+    let extension = ByteSlice::from(";admin=true");
+    let (forged_message, forged_mac) =
+        adversary::forge_length_extension_mac::<Md4, _>(&oracle, &message, &mac, &extension)
+            .expect("adversary should be successful");
+
+    assert!(forged_message.to_string().ends_with(";admin=true"));
+    assert!(oracle.verify(&forged_message, &forged_mac));
+}
+
+#[test]
+fn challenge_31() {
+    let _lock = TIMING_ATTACK
+        .lock()
+        .unwrap_or_else(|error| error.into_inner());
+    let file = ByteSlice::from("foo");
+
+    // Leak a few milliseconds after each matching byte
+    let oracle = HmacTimingLeakOracle::with_delay_and_rng(Duration::from_millis(5), &mut seeded_rng());
+
+    // Let the adversary attack the oracle
+    let signature = adversary::attack_hmac_timing_leak(&oracle, &file, 2).expect("adversary should be successful");
+
+    assert_eq!(signature, oracle.sign(&file));
+}
+
+#[test]
+fn challenge_31_server() {
+    // Any file name should survive the round trip through the percent-encoded query
+    let file = ByteSlice::from("foo bar&signature=00/%?#é");
+
+    // Serve the oracle over a loopback TCP socket
+    let oracle = HmacTimingLeakOracle::with_delay_and_rng(Duration::from_millis(5), &mut seeded_rng());
+    let signature = oracle.sign(&file);
+    let client = oracle.spawn_server().expect("server should start");
+
+    assert!(client.verify(&file, &signature));
+    assert!(!client.verify(&ByteSlice::from("foo bar"), &signature));
+}
+
+#[test]
+fn challenge_32() {
+    let _lock = TIMING_ATTACK
+        .lock()
+        .unwrap_or_else(|error| error.into_inner());
+    let file = ByteSlice::from("foo");
+
+    // Use a smaller leak of only a few milliseconds, which requires multiple samples
+    let oracle = HmacTimingLeakOracle::with_delay_and_rng(Duration::from_millis(2), &mut seeded_rng());
+
+    // Let the adversary attack the oracle
+    let signature = adversary::attack_hmac_timing_leak(&oracle, &file, 3).expect("adversary should be successful");
+
+    assert_eq!(signature, oracle.sign(&file));
+}
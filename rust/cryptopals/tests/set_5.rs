@@ -0,0 +1,292 @@
+use cryptopals::{
+    adversary::{self, AdversaryError, DhMitmStrategy, SrpZeroKeyStrategy},
+    byte::*,
+    dh, number_theory, rsa, srp,
+};
+use num_bigint::BigUint;
+use rand::seq::SliceRandom;
+use std::thread;
+// Test support
+use support::{seeded_rng, TestFile};
+mod support;
+
+const EMAIL: &str = "alice@example.com";
+
+#[test]
+fn challenge_33() {
+    let mut rng = rand::thread_rng();
+
+    // Small parameters
+    let parameters = dh::Parameters::new(BigUint::from(37_u32), BigUint::from(5_u32));
+    let alice = dh::KeyPair::new(&parameters, &mut rng);
+    let bob = dh::KeyPair::new(&parameters, &mut rng);
+    assert_eq!(alice.shared_secret(bob.public_key()), bob.shared_secret(alice.public_key()));
+
+    // NIST parameters
+    let parameters = dh::Parameters::default();
+    let alice = dh::KeyPair::new(&parameters, &mut rng);
+    let bob = dh::KeyPair::new(&parameters, &mut rng);
+    assert_eq!(alice.shared_secret(bob.public_key()), bob.shared_secret(alice.public_key()));
+}
+
+// Let Alice send messages to an echo bot, while the adversary sits in the middle
+fn exchange_messages_through_mitm(strategy: DhMitmStrategy) {
+    let messages = [
+        ByteSlice::from("Hi Bob, can you hear me?"),
+        ByteSlice::from("This message should stay between the two of us."),
+    ];
+
+    let (alice, mitm_alice) = dh::channel();
+    let (mitm_bob, bob) = dh::channel();
+
+    // Bob echoes every message
+    let bob = thread::spawn(move || {
+        let bob = dh::Party::respond(bob).expect("key exchange should succeed");
+        while let Ok(message) = bob.receive() {
+            if bob.send(message).is_err() {
+                break;
+            }
+        }
+    });
+
+    // The adversary relays all messages between Alice and Bob
+    let mitm = thread::spawn(move || adversary::attack_dh_mitm(mitm_alice, mitm_bob, strategy));
+
+    let alice = dh::Party::initiate(alice, dh::Parameters::default()).expect("key exchange should succeed");
+    for message in &messages {
+        alice.send(message.clone()).expect("message should be sent");
+        assert_eq!(&alice.receive().expect("echo should be received"), message);
+    }
+
+    // Hang up
+    drop(alice);
+    bob.join().expect("Bob should not panic");
+    let plaintexts = mitm
+        .join()
+        .expect("adversary should not panic")
+        .expect("adversary should be successful");
+
+    // The adversary has seen each message and each echo
+    let expected = messages
+        .iter()
+        .flat_map(|message| [message.clone(), message.clone()])
+        .collect::<Vec<_>>();
+    assert_eq!(plaintexts, expected);
+}
+
+#[test]
+fn challenge_34() {
+    exchange_messages_through_mitm(DhMitmStrategy::PublicKeyFixing);
+}
+
+#[test]
+fn challenge_34_consecutive_messages() {
+    let (alice, mitm_alice) = dh::channel();
+    let (mitm_bob, bob) = dh::channel();
+
+    // Bob only replies after receiving two messages in a row
+    let bob = thread::spawn(move || {
+        let bob = dh::Party::respond(bob).expect("key exchange should succeed");
+        let first = bob.receive().expect("first message should be received");
+        let second = bob.receive().expect("second message should be received");
+        bob.send(first + second).expect("reply should be sent");
+    });
+
+    let mitm = thread::spawn(move || adversary::attack_dh_mitm(mitm_alice, mitm_bob, DhMitmStrategy::PublicKeyFixing));
+
+    let alice = dh::Party::initiate(alice, dh::Parameters::default()).expect("key exchange should succeed");
+    alice
+        .send(ByteSlice::from("Hi Bob, "))
+        .expect("message should be sent");
+    alice
+        .send(ByteSlice::from("are you there?"))
+        .expect("message should be sent");
+    assert_eq!(alice.receive().expect("reply should be received"), ByteSlice::from("Hi Bob, are you there?"));
+
+    // Hang up
+    drop(alice);
+    bob.join().expect("Bob should not panic");
+    let plaintexts = mitm
+        .join()
+        .expect("adversary should not panic")
+        .expect("adversary should be successful");
+
+    let expected = ["Hi Bob, ", "are you there?", "Hi Bob, are you there?"].map(ByteSlice::from);
+    assert_eq!(plaintexts, expected);
+}
+
+#[test]
+fn challenge_35() {
+    exchange_messages_through_mitm(DhMitmStrategy::GeneratorOne);
+    exchange_messages_through_mitm(DhMitmStrategy::GeneratorP);
+    exchange_messages_through_mitm(DhMitmStrategy::GeneratorPMinusOne);
+}
+
+// Let a client log in to a server in the background, and return whether the client and server accepted the login
+fn login(server: &srp::Server, client: &srp::Client) -> (bool, bool) {
+    let (client_channel, server_channel) = srp::channel();
+
+    thread::scope(|scope| {
+        let server = scope.spawn(move || server.accept(&server_channel));
+
+        let client_result = client
+            .login(&client_channel)
+            .expect("login should follow the protocol");
+        let server_result = server
+            .join()
+            .expect("server should not panic")
+            .expect("login should follow the protocol");
+
+        (client_result, server_result)
+    })
+}
+
+#[test]
+fn challenge_36() {
+    let password = "correct horse battery staple";
+    let parameters = dh::Parameters::default();
+
+    for server in [srp::Server::new, srp::Server::simplified] {
+        let mut server = server(parameters.clone());
+        server.register(EMAIL, password);
+
+        // Correct password
+        let client = srp::Client::new(parameters.clone(), EMAIL, password);
+        assert_eq!(login(&server, &client), (true, true));
+
+        // Incorrect password
+        let client = srp::Client::new(parameters.clone(), EMAIL, "Tr0ub4dor&3");
+        assert_eq!(login(&server, &client), (false, false));
+
+        // Unknown user
+        let client = srp::Client::new(parameters.clone(), "eve@example.com", password);
+        assert_eq!(login(&server, &client), (false, false));
+    }
+}
+
+#[test]
+fn challenge_37() {
+    let parameters = dh::Parameters::default();
+
+    for strategy in [
+        SrpZeroKeyStrategy::Zero,
+        SrpZeroKeyStrategy::Modulus,
+        SrpZeroKeyStrategy::DoubleModulus,
+    ] {
+        let mut server = srp::Server::new(parameters.clone());
+        server.register(EMAIL, "correct horse battery staple");
+
+        let (adversary_channel, server_channel) = srp::channel();
+        let server = thread::spawn(move || server.accept(&server_channel));
+
+        adversary::attack_srp_zero_key(&adversary_channel, &parameters, EMAIL, strategy)
+            .expect("adversary should be successful");
+        assert!(server
+            .join()
+            .expect("server should not panic")
+            .expect("login should follow the protocol"));
+    }
+}
+
+#[test]
+fn challenge_38() {
+    let mut rng = seeded_rng();
+    let parameters = dh::Parameters::default();
+    let passwords = TestFile::new("../../data/passwords.txt")
+        .lines()
+        .collect::<Vec<_>>();
+    let password = passwords
+        .choose(&mut rng)
+        .expect("word list should not be empty");
+
+    // The adversary poses as the server
+    let (client_channel, adversary_channel) = srp::channel();
+    let adversary = {
+        let parameters = parameters.clone();
+        let passwords = passwords.clone();
+        let mut rng = rng.clone();
+        thread::spawn(move || {
+            adversary::attack_srp_dictionary(
+                &adversary_channel,
+                &parameters,
+                passwords.iter().map(String::as_str),
+                &mut rng,
+            )
+        })
+    };
+
+    // The client does not notice the attack
+    let client = srp::Client::new(parameters.clone(), EMAIL, password);
+    assert!(client
+        .login(&client_channel)
+        .expect("login should follow the protocol"));
+
+    let cracked_password = adversary
+        .join()
+        .expect("adversary should not panic")
+        .expect("adversary should be successful");
+    assert_eq!(&cracked_password, password);
+
+    // A password outside of the word list cannot be cracked
+    let (client_channel, adversary_channel) = srp::channel();
+    let adversary = thread::spawn(move || {
+        adversary::attack_srp_dictionary(
+            &adversary_channel,
+            &parameters,
+            passwords.iter().map(String::as_str),
+            &mut rng,
+        )
+    });
+    let client = srp::Client::new(dh::Parameters::default(), EMAIL, "correct horse battery staple");
+    client
+        .login(&client_channel)
+        .expect("login should follow the protocol");
+
+    let report = adversary
+        .join()
+        .expect("adversary should not panic")
+        .expect_err("password should not be in the word list");
+    assert_eq!(report.current_context(), &AdversaryError::PasswordNotInDictionary);
+}
+
+#[test]
+fn challenge_39() {
+    let mut rng = rand::thread_rng();
+
+    // Modular inverse
+    let inverse =
+        number_theory::modular_inverse(&BigUint::from(17_u32), &BigUint::from(3120_u32)).expect("inverse should exist");
+    assert_eq!(inverse, BigUint::from(2753_u32));
+
+    // Encrypt and decrypt a number
+    let private_key = rsa::PrivateKey::generate(1024, &mut rng);
+    let public_key = private_key.public_key();
+    let message = BigUint::from(42_u32);
+    assert_eq!(private_key.decrypt(&public_key.encrypt(&message)), message);
+
+    // Encrypt and decrypt a string
+    let plaintext = ByteSlice::from("Attack at dawn!");
+    let ciphertext = public_key.encrypt(&BigUint::from(&plaintext));
+    assert_eq!(ByteSlice::from(&private_key.decrypt(&ciphertext)), plaintext);
+}
+
+#[test]
+fn challenge_40() {
+    let mut rng = rand::thread_rng();
+    let plaintext = ByteSlice::from("Attack at dawn!");
+
+    // Encrypt the same plaintext to three different public keys
+    let ciphertexts = (0..3)
+        .map(|_| {
+            let public_key = rsa::PrivateKey::generate(1024, &mut rng)
+                .public_key()
+                .clone();
+            let ciphertext = public_key.encrypt(&BigUint::from(&plaintext));
+            (public_key, ciphertext)
+        })
+        .collect::<Vec<_>>();
+
+    let recovered_plaintext = adversary::attack_rsa_broadcast(&ciphertexts).expect("adversary should be successful");
+
+    assert_eq!(recovered_plaintext, plaintext);
+}
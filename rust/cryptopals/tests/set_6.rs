@@ -0,0 +1,189 @@
+use cryptopals::{
+    adversary::{self, AdversaryError},
+    byte::*,
+    dsa,
+    hash::{Hash, Sha1, Sha256},
+    oracle::{DecryptOracle, Oracle, OracleError, RsaUnpaddedOracle},
+    rsa,
+};
+use num_bigint::BigUint;
+use std::{str::FromStr, thread, time::Duration};
+// Test support
+use support::{from_hexadecimal, parse_signed_messages, seeded_rng, TestFile};
+mod support;
+
+#[test]
+fn challenge_41() {
+    let oracle = RsaUnpaddedOracle::with_rng(&mut seeded_rng());
+    let plaintext = ByteSlice::from("{time: 1356304276, social: '555-55-5555'}");
+
+    // Some other user submitted the ciphertext to the oracle
+    let ciphertext = oracle
+        .encrypt(plaintext.clone())
+        .expect("plaintext should be smaller than the modulus");
+    assert_eq!(
+        oracle
+            .decrypt(ciphertext.clone())
+            .expect("ciphertext should be valid"),
+        plaintext
+    );
+
+    // The oracle refuses to decrypt it again
+    let report = oracle
+        .decrypt(ciphertext.clone())
+        .expect_err("ciphertext should be refused");
+    assert_eq!(report.current_context(), &OracleError::AlreadyDecrypted);
+
+    let recovered_plaintext =
+        adversary::attack_rsa_unpadded(&oracle, oracle.public_key(), &ciphertext, &mut seeded_rng())
+            .expect("adversary should be successful");
+    assert_eq!(recovered_plaintext, plaintext);
+}
+
+#[test]
+fn challenge_41_retention_period() {
+    let retention_period = Duration::from_millis(50);
+    let oracle = RsaUnpaddedOracle::with_retention_period_and_rng(retention_period, &mut seeded_rng());
+    let ciphertext = oracle
+        .encrypt(ByteSlice::from("cryptopals"))
+        .expect("plaintext should be smaller than the modulus");
+
+    assert!(oracle.decrypt(ciphertext.clone()).is_ok());
+    assert!(oracle.decrypt(ciphertext.clone()).is_err());
+
+    // The ciphertext can be decrypted again once it expired
+    thread::sleep(2 * retention_period);
+    assert!(oracle.decrypt(ciphertext).is_ok());
+}
+
+#[test]
+fn challenge_42() {
+    let private_key = rsa::PrivateKey::generate(1024, &mut seeded_rng());
+    let public_key = private_key.public_key();
+    let message = ByteSlice::from("hi mom");
+
+    // A genuine signature is accepted by both verifiers
+    let signature = private_key
+        .sign::<Sha1>(&message)
+        .expect("modulus should be long enough");
+    assert!(public_key.verify::<Sha1>(&message, &signature));
+    assert!(public_key.verify_sloppy::<Sha1>(&message, &signature));
+
+    // A forged signature is only accepted by the sloppy verifier
+    let forged_signature =
+        adversary::forge_rsa_signature::<Sha1>(public_key, &message).expect("adversary should be successful");
+    assert!(public_key.verify_sloppy::<Sha1>(&message, &forged_signature));
+    assert!(!public_key.verify::<Sha1>(&message, &forged_signature));
+
+    // The forged signature is only valid for the message
+    assert!(!public_key.verify_sloppy::<Sha1>(&ByteSlice::from("hi dad"), &forged_signature));
+}
+
+#[test]
+fn challenge_42_short_modulus() {
+    let private_key = rsa::PrivateKey::generate(1024, &mut seeded_rng());
+    let message = ByteSlice::from("hi mom");
+
+    // The DigestInfo of SHA-256 is too long to leave enough garbage in a 1024-bit signature block
+    let report = adversary::forge_rsa_signature::<Sha256>(private_key.public_key(), &message)
+        .expect_err("modulus should be too short");
+    assert_eq!(report.current_context(), &AdversaryError::UnableToForgeSignature);
+}
+
+// Public key of challenge 43
+const CHALLENGE_43_PUBLIC_KEY: &str = "\
+    84ad4719d044495496a3201c8ff484feb45b962e7302e56a392aee4abab3e4bdebf2955b4736012f21a08084056b19bcd7fee56048e004e449\
+    84e2f411788efdc837a0d2e5abb7b555039fd243ac01f0fb2ed1dec568280ce678e931868d23eb095fde9d3779191b8c0299d6e07bbb283e66\
+    33451e535c45513b2d33c99ea17";
+
+// Public key of challenge 44
+const CHALLENGE_44_PUBLIC_KEY: &str = "\
+    2d026f4bf30195ede3a088da85e398ef869611d0f68f0713d51c9c1a3a26c95105d915e2d8cdf26d056b86b8a7b85519b1c23cc3ecdc606265\
+    0462e3063bd179c2a6581519f674a61f1d89a1fff27171ebc1b93d4dc57bceb7ae2430f98a6a4d83d8279ee65d71c1203d2c96d65ebbf7cce9\
+    d32971c3de5084cce04a2e147821";
+
+// Create a public key with the default domain parameters from its hexadecimal encoding
+fn dsa_public_key(y: &str) -> dsa::PublicKey {
+    let y = BigUint::parse_bytes(y.as_bytes(), 16).expect("public key should be valid hexadecimal");
+
+    dsa::PublicKey::new(dsa::Parameters::default(), y)
+}
+
+// The challenges only publish the SHA-1 of the hexadecimal encoding of the private key
+fn fingerprint(private_key: &dsa::PrivateKey) -> ByteSlice<'static> {
+    Sha1::digest(&ByteSlice::from(private_key.x().to_str_radix(16).into_bytes()))
+}
+
+#[test]
+fn challenge_43() {
+    let public_key = dsa_public_key(CHALLENGE_43_PUBLIC_KEY);
+    let message = ByteSlice::from(
+        "For those that envy a MC it can be hazardous to your health\n\
+         So be friendly, a matter of life and death, just like a etch-a-sketch\n",
+    );
+    let signature = dsa::Signature::new(
+        BigUint::from_str("548099063082341131477253921760299949438196259240").unwrap(),
+        BigUint::from_str("857042759984254168557880549501802188789837994940").unwrap(),
+    );
+    assert_eq!(dsa::hash_message(&message).to_str_radix(16), "d2d0714f014a9784047eaeccf956520045c45265");
+    assert!(public_key.verify(&message, &signature));
+
+    let private_key =
+        adversary::attack_dsa_small_nonce(&public_key, &message, &signature).expect("adversary should be successful");
+    assert_eq!(private_key.public_key(), &public_key);
+    assert_eq!(fingerprint(&private_key), from_hexadecimal("0954edd5e0afe5542a4adf012611a91912a3ec16"));
+}
+
+#[test]
+fn challenge_44() {
+    let public_key = dsa_public_key(CHALLENGE_44_PUBLIC_KEY);
+    let signed_messages = parse_signed_messages(TestFile::new("../../data/44.txt").lines());
+    for (message, signature) in &signed_messages {
+        assert!(public_key.verify(message, signature));
+    }
+
+    let private_key =
+        adversary::attack_dsa_repeated_nonce(&public_key, &signed_messages).expect("adversary should be successful");
+    assert_eq!(fingerprint(&private_key), from_hexadecimal("ca8f6f7c66fa362d40760d135b763eb8527d3d52"));
+
+    // Without a reused nonce the private key cannot be recovered
+    let report =
+        adversary::attack_dsa_repeated_nonce(&public_key, &signed_messages[..5]).expect_err("nonces should be unique");
+    assert_eq!(report.current_context(), &AdversaryError::UnableToRecoverPrivateKey);
+}
+
+#[test]
+fn challenge_45() {
+    let mut rng = seeded_rng();
+    let parameters = dsa::Parameters::default();
+    let private_key = dsa::PrivateKey::generate(parameters.clone(), &mut rng);
+    let y = private_key.public_key().y().clone();
+    let messages = [
+        ByteSlice::from("Hello, world"),
+        ByteSlice::from("Goodbye, world"),
+    ];
+
+    // Signing with g = 0 results in r = 0, which only passes a verifier that does not check the range of r
+    let tampered_parameters =
+        dsa::Parameters::new(parameters.p().clone(), parameters.q().clone(), BigUint::from(0_u32));
+    let public_key = dsa::PublicKey::new(tampered_parameters, y.clone());
+    let signature = adversary::forge_dsa_signature(&public_key, &mut rng).expect("adversary should be successful");
+    for message in &messages {
+        assert!(public_key.verify_unchecked(message, &signature));
+        assert!(!public_key.verify(message, &signature));
+    }
+
+    // With g = p + 1 the magic signature passes any verifier
+    let tampered_parameters =
+        dsa::Parameters::new(parameters.p().clone(), parameters.q().clone(), parameters.p() + 1_u32);
+    let public_key = dsa::PublicKey::new(tampered_parameters, y);
+    let signature = adversary::forge_dsa_signature(&public_key, &mut rng).expect("adversary should be successful");
+    for message in &messages {
+        assert!(public_key.verify(message, &signature));
+    }
+
+    // The genuine parameters cannot be exploited
+    let report = adversary::forge_dsa_signature(private_key.public_key(), &mut rng)
+        .expect_err("generator should not be tampered");
+    assert_eq!(report.current_context(), &AdversaryError::GeneratorNotTampered);
+}
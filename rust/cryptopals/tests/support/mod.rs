@@ -1,7 +1,8 @@
 #![allow(dead_code)]
 
 use byte_encoding::{decode_base64, decode_hexadecimal};
-use cryptopals::byte::*;
+use cryptopals::{byte::*, dsa, rng::Mt19937};
+use num_bigint::BigUint;
 pub use test_file::TestFile;
 
 mod test_file;
@@ -32,3 +33,49 @@ pub fn funky_music() -> ByteSlice<'static> {
     // Parse as hexadecimal
     from_hexadecimal(value)
 }
+
+// Returns a random number generator for the oracles of a test case. The seed is printed, which is only shown for failing
+// tests, and can be pinned using the `CRYPTOPALS_SEED` environment variable in order to reproduce a failure.
+pub fn seeded_rng() -> Mt19937 {
+    let seed = std::env::var("CRYPTOPALS_SEED")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or_else(rand::random);
+    println!("seed: {}", seed);
+
+    Mt19937::new(seed)
+}
+
+// Parse signed messages in the format of challenge 44, where each message consists of the lines `msg: `, `s: `, `r: `
+// and `m: `. The hash `m` is verified, but not returned.
+pub fn parse_signed_messages<S>(lines: impl IntoIterator<Item = S>) -> Vec<(ByteSlice<'static>, dsa::Signature)>
+where
+    S: AsRef<str>,
+{
+    let lines = lines.into_iter().collect::<Vec<_>>();
+    assert_eq!(lines.len() % 4, 0, "every message should consist of four lines");
+
+    lines
+        .chunks(4)
+        .map(|chunk| {
+            let field = |index: usize, name: &str| {
+                chunk[index]
+                    .as_ref()
+                    .strip_prefix(name)
+                    .and_then(|line| line.strip_prefix(": "))
+                    .unwrap_or_else(|| panic!("line should contain field {}", name))
+                    .to_string()
+            };
+            let integer = |value: String, radix: u32| {
+                BigUint::parse_bytes(value.as_bytes(), radix).expect("field should contain an integer")
+            };
+
+            let message = ByteSlice::from(field(0, "msg").into_bytes());
+            let s = integer(field(1, "s"), 10);
+            let r = integer(field(2, "r"), 10);
+            assert_eq!(dsa::hash_message(&message), integer(field(3, "m"), 16), "hash should match message");
+
+            (message, dsa::Signature::new(r, s))
+        })
+        .collect()
+}
@@ -23,6 +23,7 @@ impl TestFile {
             .map(|result| result.expect("there should be no I/O errors in test cases"))
     }
 
+    #[allow(clippy::inherent_to_string, clippy::wrong_self_convention)]
     pub fn to_string(self) -> String {
         // Use `BufReader::lines` instead `Read::read_to_end` in order to remove newline characters
         self.lines().fold(String::new(), |mut acc, line| {